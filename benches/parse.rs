@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scpsl_api::{
+    fixtures::SERVER_INFO_FULL,
+    server_info::{raw::RawResponse, strip_markup, Response},
+};
+use serde_json::Value;
+use std::convert::TryFrom;
+
+/// Builds a `serverinfo` payload with `count` servers by repeating the servers of [`SERVER_INFO_FULL`].
+fn large_payload(count: usize) -> String {
+    let mut value: Value = serde_json::from_str(SERVER_INFO_FULL).unwrap();
+    let servers = value["Servers"].as_array().unwrap().clone();
+
+    value["Servers"] = Value::Array(servers.into_iter().cycle().take(count).collect());
+    value.to_string()
+}
+
+fn deserialize(c: &mut Criterion) {
+    let payload = large_payload(1000);
+
+    c.bench_function("deserialize RawResponse (1000 servers)", |b| {
+        b.iter(|| serde_json::from_str::<RawResponse>(black_box(payload.as_str())).unwrap())
+    });
+}
+
+fn convert(c: &mut Criterion) {
+    let raw: RawResponse = serde_json::from_str(large_payload(1000).as_str()).unwrap();
+
+    c.bench_function("convert RawResponse into Response (1000 servers)", |b| {
+        b.iter(|| Response::try_from(black_box(raw.clone())).unwrap())
+    });
+}
+
+fn markup(c: &mut Criterion) {
+    let info =
+        "<color=#ff0000><size=40>★ My Server ★</size></color>\n<b>Vanilla+</b> | <i>EU</i>\n"
+            .repeat(100);
+
+    c.bench_function("strip_markup", |b| {
+        b.iter(|| strip_markup(black_box(info.as_str())))
+    });
+}
+
+criterion_group!(benches, deserialize, convert, markup);
+criterion_main!(benches);
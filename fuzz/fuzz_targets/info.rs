@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use scpsl_api::server_info::{raw::RawServerInfo, ServerInfo};
+use serde_json::json;
+use std::convert::TryFrom;
+
+fuzz_target!(|data: &str| {
+    let raw: RawServerInfo =
+        serde_json::from_value(json!({ "ID": 1, "Port": 7777, "Info": data })).unwrap();
+    let _ = ServerInfo::try_from(raw);
+});
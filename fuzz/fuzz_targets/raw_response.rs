@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use scpsl_api::server_info::{raw::RawResponse, Response};
+use std::convert::TryFrom;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(raw) = serde_json::from_slice::<RawResponse>(data) {
+        let _ = Response::try_from(raw);
+    }
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use scpsl_api::server_info::strip_markup;
+
+fuzz_target!(|data: &str| {
+    let _ = strip_markup(data);
+});
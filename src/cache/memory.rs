@@ -0,0 +1,66 @@
+use super::{CacheStore, Error};
+use crate::time::Instant;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+/// A struct representing an in-process [`CacheStore`].
+/// Expired entries are removed on writes, entries with a ttl too large to represent never expire.
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, (Option<Instant>, Bytes)>>,
+}
+
+impl MemoryCache {
+    /// Returns a new instance of the [`MemoryCache`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl CacheStore for MemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<Bytes>, Error> {
+        let entries = self.entries.lock().unwrap();
+
+        Ok(entries
+            .get(key)
+            .filter(|(expires, _)| expires.is_none_or(|expires| expires > Instant::now()))
+            .map(|(_, value)| value.clone()))
+    }
+
+    async fn put(&self, key: &str, value: Bytes, ttl: Duration) -> Result<(), Error> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        entries.retain(|_, (expires, _)| expires.is_none_or(|expires| expires > now));
+        entries.insert(key.to_string(), (now.checked_add(ttl), value));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryCache;
+    use crate::cache::CacheStore;
+    use std::time::Duration;
+
+    #[test]
+    fn huge_ttl_never_expires() {
+        let cache = MemoryCache::new();
+
+        pollster::block_on(cache.put("key", "value".into(), Duration::MAX)).unwrap();
+
+        assert_eq!(
+            pollster::block_on(cache.get("key")).unwrap(),
+            Some("value".into())
+        );
+    }
+}
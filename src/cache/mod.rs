@@ -0,0 +1,57 @@
+//! This module contains the [`CacheStore`] trait these can be used
+//! for serving repeated `serverinfo` requests made by a [`Client`](crate::Client) locally.
+//! The in-process [`MemoryCache`] is always available, the concurrent size-bounded `MokaCache`
+//! is available with the `moka` feature and the Redis-based `RedisCache`,
+//! shareable between processes, is available with the `redis` feature.
+//! # Examples
+#![cfg_attr(feature = "reqwest", doc = "```")]
+#![cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+//! use scpsl_api::{cache::MemoryCache, Client};
+//! use std::time::Duration;
+//!
+//! // Responses are kept for the cooldown returned with them.
+//! let client = Client::builder().cache(MemoryCache::new()).build().unwrap();
+//! // Responses are kept for 30 seconds.
+//! let client = Client::builder()
+//!     .cache(MemoryCache::new())
+//!     .cache_ttl(Duration::from_secs(30))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+mod memory;
+#[cfg(all(feature = "moka", not(target_arch = "wasm32")))]
+mod moka;
+#[cfg(all(feature = "redis", not(target_arch = "wasm32")))]
+mod redis;
+
+pub use self::memory::MemoryCache;
+#[cfg(all(feature = "moka", not(target_arch = "wasm32")))]
+pub use self::moka::MokaCache;
+#[cfg(all(feature = "redis", not(target_arch = "wasm32")))]
+pub use self::redis::RedisCache;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::time::Duration;
+
+/// A type representing an error of a [`CacheStore`].
+/// The [`Client`](crate::Client) treats failed reads as misses and ignores failed writes.
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// A trait representing a key-value store with expiring entries.
+/// Keys are `serverinfo` request urls with the API key redacted and its SHA-256 hash as the fragment,
+/// so entries of different API keys are kept apart.
+/// On `wasm32` implementations should use `#[async_trait(?Send)]` instead of `#[async_trait]`.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait CacheStore: Send + Sync {
+    /// Returns the value stored for the `key` if it hasn't expired yet.
+    /// # Errors
+    /// Returns [`Error`] if the store couldn't be read.
+    async fn get(&self, key: &str) -> Result<Option<Bytes>, Error>;
+
+    /// Stores the `value` for the `key` for `ttl`.
+    /// # Errors
+    /// Returns [`Error`] if the store couldn't be written.
+    async fn put(&self, key: &str, value: Bytes, ttl: Duration) -> Result<(), Error>;
+}
@@ -0,0 +1,83 @@
+use super::{CacheStore, Error};
+use async_trait::async_trait;
+use bytes::Bytes;
+use moka::{sync::Cache, Expiry};
+use std::{
+    convert::TryFrom,
+    time::{Duration, Instant},
+};
+
+type Entry = (Duration, Bytes);
+
+/// A struct representing a concurrent [`CacheStore`] implemented with [`moka`],
+/// bounded by the total size of the stored response bodies.
+/// Cloning is cheap and clones share the same entries.
+/// # Examples
+#[cfg_attr(feature = "reqwest", doc = "```")]
+#[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+/// use scpsl_api::{cache::MokaCache, Client};
+///
+/// // Keep at most 16 MiB of responses.
+/// let client = Client::builder()
+///     .cache(MokaCache::new(16 * 1024 * 1024))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct MokaCache {
+    cache: Cache<String, Entry>,
+}
+
+impl MokaCache {
+    /// Returns a new instance of the [`MokaCache`] storing up to `max_bytes` of response bodies.
+    pub fn new(max_bytes: u64) -> Self {
+        Self {
+            cache: Cache::builder()
+                .max_capacity(max_bytes)
+                .weigher(|_, (_, value): &Entry| u32::try_from(value.len()).unwrap_or(u32::MAX))
+                .expire_after(EntryExpiry)
+                .build(),
+        }
+    }
+
+    /// Get a reference to the moka cache's cache.
+    pub fn cache(&self) -> &Cache<String, (Duration, Bytes)> {
+        &self.cache
+    }
+}
+
+struct EntryExpiry;
+
+impl Expiry<String, Entry> for EntryExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        (ttl, _): &Entry,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(*ttl)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        (ttl, _): &Entry,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(*ttl)
+    }
+}
+
+#[async_trait]
+impl CacheStore for MokaCache {
+    async fn get(&self, key: &str) -> Result<Option<Bytes>, Error> {
+        Ok(self.cache.get(key).map(|(_, value)| value))
+    }
+
+    async fn put(&self, key: &str, value: Bytes, ttl: Duration) -> Result<(), Error> {
+        self.cache.insert(key.to_string(), (ttl, value));
+
+        Ok(())
+    }
+}
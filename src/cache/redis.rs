@@ -0,0 +1,84 @@
+use super::{CacheStore, Error};
+use async_trait::async_trait;
+use bytes::Bytes;
+use redis::{aio::ConnectionManager, AsyncCommands, RedisResult};
+use std::time::Duration;
+
+/// A struct representing a [`CacheStore`] backed by Redis, so the cache can be shared between processes.
+/// Cloning is cheap and clones share the same connection.
+/// # Examples
+/// ```no_run
+/// use scpsl_api::{cache::RedisCache, Client};
+///
+/// # async fn example() -> redis::RedisResult<()> {
+/// let cache = RedisCache::connect("redis://127.0.0.1/").await?;
+/// let client = Client::builder().cache(cache).build().unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RedisCache {
+    connection: ConnectionManager,
+    prefix: String,
+}
+
+impl RedisCache {
+    /// The default prefix of the keys.
+    pub const DEFAULT_PREFIX: &'static str = "scpsl-api:";
+
+    /// Returns a new instance of the [`RedisCache`] using the given `connection`.
+    pub fn new(connection: ConnectionManager) -> Self {
+        Self {
+            connection,
+            prefix: Self::DEFAULT_PREFIX.to_string(),
+        }
+    }
+
+    /// Connects to the Redis server at `url` and returns a new instance of the [`RedisCache`].
+    /// # Errors
+    /// Returns [`redis::RedisError`] if the url is invalid or the connection couldn't be established.
+    pub async fn connect(url: &str) -> RedisResult<Self> {
+        let client = redis::Client::open(url)?;
+
+        Ok(Self::new(ConnectionManager::new(client).await?))
+    }
+
+    /// Returns the [`RedisCache`] prefixing keys with `prefix` instead of [`RedisCache::DEFAULT_PREFIX`].
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Get a reference to the redis cache's prefix.
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_str()
+    }
+}
+
+#[async_trait]
+impl CacheStore for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<Bytes>, Error> {
+        let value: Option<Vec<u8>> = self
+            .connection
+            .clone()
+            .get(format!("{}{}", self.prefix, key))
+            .await?;
+
+        Ok(value.map(Bytes::from))
+    }
+
+    async fn put(&self, key: &str, value: Bytes, ttl: Duration) -> Result<(), Error> {
+        let milliseconds = (ttl.as_millis() as u64).max(1);
+
+        self.connection
+            .clone()
+            .pset_ex::<_, _, ()>(
+                format!("{}{}", self.prefix, key),
+                value.as_ref(),
+                milliseconds,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
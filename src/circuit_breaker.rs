@@ -0,0 +1,167 @@
+//! This module contains the [`CircuitBreaker`] struct these can be used
+//! for failing requests locally while the API is unavailable instead of hammering it.
+//! # Examples
+#![cfg_attr(feature = "reqwest", doc = "```")]
+#![cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+//! use scpsl_api::{circuit_breaker::CircuitBreaker, Client};
+//! use std::time::Duration;
+//!
+//! // Stop sending requests for a minute after 5 consecutive failures.
+//! let client = Client::builder()
+//!     .circuit_breaker(CircuitBreaker::new(5, Duration::from_secs(60)))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::{
+    retry::is_transient,
+    time::Instant,
+    transport::{Error, ErrorKind},
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// An enum representing a state of the [`CircuitBreaker`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CircuitState {
+    /// Requests are performed.
+    Closed,
+    /// Requests fail with [`ErrorKind::CircuitOpen`] without being performed.
+    Open,
+    /// A single probe request is performed to check whether the API is available again,
+    /// other requests fail with [`ErrorKind::CircuitOpen`].
+    /// If the probe doesn't complete in `reset_timeout` (e.g. it was cancelled), another one is let through.
+    HalfOpen,
+}
+
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { since: Instant },
+}
+
+/// A struct representing a circuit breaker which opens after `failure_threshold` consecutive
+/// transient failures (see [`is_transient`]) and lets a probe request through after `reset_timeout`.
+/// A probe that is cancelled before its outcome is recorded is replaced after another `reset_timeout`.
+/// Cloning is cheap and clones share the same state.
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+impl CircuitBreaker {
+    /// Returns a new instance of the [`CircuitBreaker`].
+    /// # Panics
+    /// Panics if `failure_threshold` is zero.
+    pub fn new(failure_threshold: u32, reset_timeout: Duration) -> Self {
+        assert!(failure_threshold != 0, "failure_threshold must not be zero");
+
+        Self {
+            failure_threshold,
+            reset_timeout,
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    /// Get a reference to the circuit breaker's failure threshold.
+    pub fn failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+
+    /// Get a reference to the circuit breaker's reset timeout.
+    pub fn reset_timeout(&self) -> Duration {
+        self.reset_timeout
+    }
+
+    /// Returns the current state of the circuit breaker.
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if until <= Instant::now() => CircuitState::HalfOpen,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Checks whether a request may be performed, moving the circuit breaker to the half-open state
+    /// if the request is the probe.
+    /// # Errors
+    /// Returns [`Error`] of kind [`ErrorKind::CircuitOpen`] if the request shouldn't be performed.
+    pub fn check(&self) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+
+        match *state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } if until <= now => {
+                *state = State::HalfOpen { since: now };
+
+                Ok(())
+            }
+            // A probe which didn't record its outcome in time was most likely cancelled.
+            State::HalfOpen { since } if since + self.reset_timeout <= now => {
+                *state = State::HalfOpen { since: now };
+
+                Ok(())
+            }
+            State::Open { .. } | State::HalfOpen { .. } => Err(Error::new(
+                ErrorKind::CircuitOpen,
+                "the API is considered unavailable",
+            )),
+        }
+    }
+
+    /// Records the outcome of a performed request: [`None`] on success or the transport error it failed with.
+    /// Non-transient errors (e.g. 4xx responses) are considered successes, as the API did respond.
+    pub fn record(&self, error: Option<&Error>) {
+        let mut state = self.state.lock().unwrap();
+
+        if error.is_some_and(is_transient) {
+            let failures = match *state {
+                State::Closed { failures } => failures + 1,
+                _ => self.failure_threshold,
+            };
+
+            *state = if failures >= self.failure_threshold {
+                State::Open {
+                    until: Instant::now() + self.reset_timeout,
+                }
+            } else {
+                State::Closed { failures }
+            };
+        } else {
+            *state = State::Closed { failures: 0 };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CircuitBreaker, CircuitState};
+    use crate::transport::Error;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn cancelled_probe_is_replaced() {
+        let circuit_breaker = CircuitBreaker::new(1, Duration::from_millis(20));
+
+        circuit_breaker.record(Some(&Error::status(503)));
+        assert!(circuit_breaker.check().is_err());
+
+        thread::sleep(Duration::from_millis(30));
+        // The probe is let through but never records its outcome.
+        assert!(circuit_breaker.check().is_ok());
+        assert!(circuit_breaker.check().is_err());
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+
+        thread::sleep(Duration::from_millis(30));
+        assert!(circuit_breaker.check().is_ok());
+
+        circuit_breaker.record(None);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    }
+}
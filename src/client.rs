@@ -0,0 +1,927 @@
+//! This module contains the [`Client`] struct these can be used
+//! for performing multiple API requests with a shared connection pool.
+
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use crate::{
+    cache::CacheStore,
+    circuit_breaker::CircuitBreaker,
+    ip::{self, IpResponse},
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+    server_info::{
+        self,
+        raw::{self, RawResponse},
+        RequestParameters, Response, Timed,
+    },
+    time::Instant,
+    transport::{self, HttpResponse, HttpTransport},
+};
+#[cfg(feature = "steam")]
+use crate::{
+    server_info::Player,
+    steam::{self, SteamProfile},
+};
+#[cfg(feature = "reqwest")]
+use reqwest::header::HeaderMap;
+#[cfg(feature = "tower")]
+use std::task::Context;
+use std::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::Poll,
+    time::Duration,
+};
+use url::Url;
+
+/// The default of [`ClientBuilder::max_retry_after`].
+const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// A struct representing an API client.
+/// It owns a [`HttpTransport`] (by default a [`reqwest::Client`]), so connections, TLS sessions
+/// and DNS lookups are reused between requests. Cloning is cheap and clones share the same transport.
+/// # Examples
+#[cfg_attr(feature = "reqwest", doc = "```no_run")]
+#[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+/// use scpsl_api::{server_info::RequestParameters, Client};
+/// use url::Url;
+///
+/// # async fn example() -> Result<(), scpsl_api::server_info::Error> {
+/// let client = Client::new();
+/// let parameters = RequestParameters::builder()
+///     .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+///     .build();
+///
+/// loop {
+///     let response = client.get_server_info(&parameters).await?;
+///     // ...
+/// #   break Ok(());
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client {
+    transport: Arc<dyn HttpTransport>,
+    retry: Option<Arc<dyn RetryPolicy>>,
+    max_retry_after: Duration,
+    cooldown: Option<CooldownMode>,
+    cooldown_until: Arc<Mutex<Option<Instant>>>,
+    cache: Option<Arc<dyn CacheStore>>,
+    cache_ttl: Option<Duration>,
+    rate_limiter: Option<RateLimiter>,
+    circuit_breaker: Option<CircuitBreaker>,
+    hedge_delay: Option<Duration>,
+}
+
+/// An enum representing how a [`Client`] handles `serverinfo` requests attempted
+/// before the cooldown returned with the previous response has passed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CooldownMode {
+    /// Wait until the cooldown passes and then perform the request.
+    Wait,
+    /// Return [`server_info::Error::CooldownActive`] without performing the request.
+    Error,
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for Client {
+    fn default() -> Self {
+        Self::with_transport(ReqwestTransport::new())
+    }
+}
+
+impl Client {
+    /// Returns a new instance of the [`Client`] using the [`ReqwestTransport`].
+    #[cfg(feature = "reqwest")]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a new instance of the [`ClientBuilder`].
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Returns a new instance of the [`Client`] performing requests through the given `http` client,
+    /// so its configuration (proxies, certificates, timeouts, etc.) applies to all API requests.
+    #[cfg(feature = "reqwest")]
+    pub fn with_http_client(http: reqwest::Client) -> Self {
+        Self::with_transport(ReqwestTransport::from(http))
+    }
+
+    /// Returns a new instance of the [`Client`] performing requests through the given `transport`.
+    pub fn with_transport<T: HttpTransport + 'static>(transport: T) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            retry: None,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
+            cooldown: None,
+            cooldown_until: Default::default(),
+            cache: None,
+            cache_ttl: None,
+            rate_limiter: None,
+            circuit_breaker: None,
+            hedge_delay: None,
+        }
+    }
+
+    /// Get a reference to the client's transport.
+    pub fn transport(&self) -> &dyn HttpTransport {
+        self.transport.as_ref()
+    }
+
+    /// Returns the time left until the cooldown returned with the last successful `serverinfo` response passes.
+    /// Returns [`None`] if it has already passed or the client was built without a [`CooldownMode`],
+    /// as the cooldown is only tracked to enforce it.
+    pub fn cooldown_remaining(&self) -> Option<Duration> {
+        let until = (*self.cooldown_until.lock().unwrap())?;
+        let remaining = until.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            None
+        } else {
+            Some(remaining)
+        }
+    }
+
+    /// Returns info about own servers. See [`server_info::get`].
+    /// Successful responses are served from the cache if the client was built with one.
+    /// # Errors
+    /// See [`server_info::get`].
+    /// Returns [`server_info::Error::CooldownActive`] if the client was built with [`CooldownMode::Error`]
+    /// and the cooldown hasn't passed yet.
+    pub async fn get_server_info(
+        &self,
+        parameters: &RequestParameters,
+    ) -> Result<Response, server_info::Error> {
+        let (_, response) = self.get_raw_response(parameters).await?;
+
+        Ok(Response::from_raw(response, parameters.parse_mode())?)
+    }
+
+    /// Returns info about own servers together with the request durations.
+    /// The total duration includes retries and waiting for the cooldown,
+    /// connection phase durations are [`None`] for cached responses.
+    /// # Errors
+    /// See [`Client::get_server_info`].
+    pub async fn get_server_info_timed(
+        &self,
+        parameters: &RequestParameters,
+    ) -> Result<Timed<Response>, server_info::Error> {
+        let start = Instant::now();
+        let (http_response, response) = self.get_raw_response(parameters).await?;
+
+        Ok(Timed::new(
+            Response::from_raw(response, parameters.parse_mode())?,
+            start.elapsed(),
+            http_response
+                .map(|http_response| http_response.timings())
+                .unwrap_or_default(),
+        ))
+    }
+
+    /// Returns raw info about own servers. See [`raw::get`].
+    /// Successful responses are served from the cache if the client was built with one.
+    /// # Errors
+    /// See [`Client::get_server_info`].
+    #[cfg(feature = "raw")]
+    pub async fn get_server_info_raw(
+        &self,
+        parameters: &RequestParameters,
+    ) -> Result<RawResponse, server_info::Error> {
+        self.get_raw_response(parameters)
+            .await
+            .map(|(_, response)| response)
+    }
+
+    /// Returns current ip. See [`ip::get`].
+    /// # Errors
+    /// See [`ip::get`].
+    pub async fn get_ip(&self, url: Url) -> Result<IpResponse, ip::Error> {
+        self.get_ip_with_fallbacks(url, &[]).await
+    }
+
+    /// Returns current ip, trying the `primary` url and then each of the `fallbacks` in order
+    /// until one of them succeeds. See [`ip::get_with_fallbacks`].
+    /// # Errors
+    /// See [`ip::get_with_fallbacks`].
+    pub async fn get_ip_with_fallbacks(
+        &self,
+        primary: Url,
+        fallbacks: &[Url],
+    ) -> Result<IpResponse, ip::Error> {
+        self.with_retries(|| ip::fetch(self.transport(), &primary, fallbacks))
+            .await
+    }
+
+    /// Returns Steam profiles through the client's transport. See [`steam::get_profiles`].
+    /// The requests aren't retried, rate limited or gated by the circuit breaker,
+    /// as these only apply to the SCP: SL API.
+    /// # Errors
+    /// See [`steam::get_profiles`].
+    #[cfg(feature = "steam")]
+    pub async fn get_steam_profiles(
+        &self,
+        url: &Url,
+        key: &str,
+        steam_ids: &[&str],
+    ) -> Result<Vec<SteamProfile>, steam::Error> {
+        steam::fetch_profiles(self.transport(), url, key, steam_ids).await
+    }
+
+    /// Attaches Steam profiles to the `@steam` players among `players` through the client's transport.
+    /// See [`steam::enrich`] and [`Client::get_steam_profiles`].
+    /// # Errors
+    /// See [`steam::get_profiles`].
+    #[cfg(feature = "steam")]
+    pub async fn enrich_steam_profiles<'a, I>(
+        &self,
+        url: &Url,
+        key: &str,
+        players: I,
+    ) -> Result<(), steam::Error>
+    where
+        I: IntoIterator<Item = &'a mut Player>,
+    {
+        steam::attach_profiles(self.transport(), url, key, players).await
+    }
+
+    /// Returns the parsed response and the HTTP response it was parsed from, or [`None`] if it was cached.
+    async fn get_raw_response(
+        &self,
+        parameters: &RequestParameters,
+    ) -> Result<(Option<HttpResponse>, RawResponse), server_info::Error> {
+        let key = self.cache.as_ref().map(|_| raw::cache_key(parameters));
+
+        if let (Some(cache), Some(key)) = (&self.cache, &key) {
+            if let Ok(Some(body)) = cache.get(key).await {
+                return raw::parse_with_mode(&body, None, parameters.parse_mode())
+                    .map(|response| (None, response));
+            }
+        }
+
+        self.wait_cooldown().await?;
+
+        let (http_response, response) = self
+            .with_retries(|| raw::fetch_with_http_response(self.transport(), parameters))
+            .await?;
+
+        if let (true, Some(cooldown)) = (response.success, response.cooldown) {
+            self.set_cooldown(cooldown);
+
+            let ttl = self.cache_ttl.unwrap_or(Duration::from_secs(cooldown));
+
+            if let (Some(cache), Some(key), false) = (&self.cache, key, ttl.is_zero()) {
+                // The cache is only an optimization, so failed writes are ignored.
+                let _ = cache.put(&key, http_response.body().clone(), ttl).await;
+            }
+        }
+
+        Ok((Some(http_response), response))
+    }
+
+    async fn wait_cooldown(&self) -> Result<(), server_info::Error> {
+        if let (Some(mode), Some(remaining)) = (self.cooldown, self.cooldown_remaining()) {
+            match mode {
+                CooldownMode::Wait => self.transport.sleep(remaining).await,
+                CooldownMode::Error => {
+                    return Err(server_info::Error::CooldownActive { remaining })
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_cooldown(&self, seconds: u64) {
+        if self.cooldown.is_some() {
+            *self.cooldown_until.lock().unwrap() =
+                Instant::now().checked_add(Duration::from_secs(seconds));
+        }
+    }
+
+    async fn with_retries<T, E, R, Fut>(&self, mut request: R) -> Result<T, E>
+    where
+        R: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: RequestError,
+    {
+        let mut attempt = 1;
+
+        loop {
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                circuit_breaker.check()?;
+            }
+            if let Some(rate_limiter) = &self.rate_limiter {
+                let wait = rate_limiter.reserve();
+
+                if !wait.is_zero() {
+                    self.transport.sleep(wait).await;
+                }
+            }
+
+            let mut result = match self.hedge_delay {
+                Some(delay) => {
+                    hedge(request(), self.transport.sleep(delay), || {
+                        // The hedged request isn't worth waiting for the rate limiter.
+                        let allowed = self
+                            .rate_limiter
+                            .as_ref()
+                            .is_none_or(RateLimiter::try_acquire);
+
+                        allowed.then(&mut request)
+                    })
+                    .await
+                }
+                None => request().await,
+            };
+
+            if let Some(error) = result.as_mut().err().and_then(E::transport_error_mut) {
+                error.set_attempt(attempt);
+            }
+            if let Some(circuit_breaker) = &self.circuit_breaker {
+                circuit_breaker.record(result.as_ref().err().and_then(E::transport_error));
+            }
+
+            let delay = match (&result, &self.retry) {
+                (Err(error), Some(retry)) if error.is_rate_limited() => {
+                    // The policy decides whether to retry, the API may ask to wait longer.
+                    retry
+                        .retry_after(&transport::Error::status(429), attempt)
+                        .map(|delay| {
+                            error
+                                .retry_after()
+                                .map_or(delay, |after| after.min(self.max_retry_after).max(delay))
+                        })
+                }
+                (Err(error), Some(retry)) => error
+                    .transport_error()
+                    .and_then(|error| retry.retry_after(error, attempt)),
+                _ => None,
+            };
+
+            match delay {
+                Some(delay) => {
+                    self.transport.sleep(delay).await;
+                    attempt += 1;
+                }
+                _ => return result,
+            }
+        }
+    }
+}
+
+/// Returns the output of `first` or, if it doesn't complete before `delay`, of whichever of `first`
+/// and the future returned by `second` completes first. The other future is dropped, cancelling it.
+async fn hedge<F, D, S>(first: F, delay: D, second: S) -> F::Output
+where
+    F: Future,
+    D: Future<Output = ()>,
+    S: FnOnce() -> Option<F>,
+{
+    let mut first = Box::pin(first);
+    // The delay is dropped once it completes, as it must not be polled again
+    // even if `second` returned no future.
+    let mut sleep = Some(Box::pin(delay));
+    let mut second = Some(second);
+    let mut hedged: Option<Pin<Box<F>>> = None;
+
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = first.as_mut().poll(cx) {
+            return Poll::Ready(output);
+        }
+        if let Some(hedged) = &mut hedged {
+            return hedged.as_mut().poll(cx);
+        }
+        if sleep
+            .as_mut()
+            .is_some_and(|sleep| sleep.as_mut().poll(cx).is_ready())
+        {
+            sleep = None;
+
+            if let Some(future) = second.take().and_then(|second| second()) {
+                let mut future = Box::pin(future);
+
+                if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                    return Poll::Ready(output);
+                }
+                hedged = Some(future);
+            }
+        }
+
+        Poll::Pending
+    })
+    .await
+}
+
+/// A trait representing an error of a request retried by [`Client::with_retries`].
+trait RequestError: From<transport::Error> {
+    /// Returns the transport error the request failed with, if any.
+    fn transport_error(&self) -> Option<&transport::Error>;
+
+    /// Returns a mutable reference to the transport error the request failed with, if any.
+    fn transport_error_mut(&mut self) -> Option<&mut transport::Error>;
+
+    /// Returns `true` if the request was rejected because of the rate limit.
+    fn is_rate_limited(&self) -> bool {
+        false
+    }
+
+    /// Returns the delay the API asked to wait before retrying the request.
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl RequestError for server_info::Error {
+    fn transport_error(&self) -> Option<&transport::Error> {
+        match self {
+            server_info::Error::TransportError(error) | server_info::Error::ServerError(error) => {
+                Some(error)
+            }
+            _ => None,
+        }
+    }
+
+    fn transport_error_mut(&mut self) -> Option<&mut transport::Error> {
+        match self {
+            server_info::Error::TransportError(error) | server_info::Error::ServerError(error) => {
+                Some(error)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_rate_limited(&self) -> bool {
+        matches!(self, server_info::Error::RateLimited { .. })
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            server_info::Error::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl RequestError for ip::Error {
+    fn transport_error(&self) -> Option<&transport::Error> {
+        match self {
+            ip::Error::TransportError(error) => Some(error),
+            ip::Error::AddrParseError(_) => None,
+        }
+    }
+
+    fn transport_error_mut(&mut self) -> Option<&mut transport::Error> {
+        match self {
+            ip::Error::TransportError(error) => Some(error),
+            ip::Error::AddrParseError(_) => None,
+        }
+    }
+}
+
+/// Makes the [`Client`] usable in [`tower`](https://docs.rs/tower) middleware stacks.
+/// The service is always ready, calls are equivalent to [`Client::get_server_info`].
+/// # Examples
+#[cfg_attr(feature = "reqwest", doc = "```no_run")]
+#[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+/// use scpsl_api::{server_info::RequestParameters, Client};
+/// use tower_service::Service;
+/// use url::Url;
+///
+/// # async fn example() -> Result<(), scpsl_api::server_info::Error> {
+/// let mut client = Client::new();
+/// let parameters = RequestParameters::builder()
+///     .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+///     .build();
+///
+/// let response = client.call(parameters).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tower")]
+impl tower_service::Service<RequestParameters> for Client {
+    type Response = Response;
+    type Error = server_info::Error;
+    #[cfg(not(target_arch = "wasm32"))]
+    type Future = Pin<Box<dyn Future<Output = Result<Response, server_info::Error>> + Send>>;
+    #[cfg(target_arch = "wasm32")]
+    type Future = Pin<Box<dyn Future<Output = Result<Response, server_info::Error>>>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, parameters: RequestParameters) -> Self::Future {
+        let client = self.clone();
+
+        Box::pin(async move { client.get_server_info(&parameters).await })
+    }
+}
+
+/// A struct representing a builder for the [`Client`].
+#[derive(Default)]
+pub struct ClientBuilder {
+    transport: Option<Arc<dyn HttpTransport>>,
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    connect_timeout: Option<Duration>,
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    timeout: Option<Duration>,
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    proxies: Vec<reqwest::Proxy>,
+    #[cfg(feature = "reqwest")]
+    user_agent: Option<String>,
+    #[cfg(feature = "reqwest")]
+    default_headers: HeaderMap,
+    #[cfg(feature = "reqwest")]
+    max_body_size: Option<u64>,
+    retry: Option<Arc<dyn RetryPolicy>>,
+    max_retry_after: Option<Duration>,
+    cooldown: Option<CooldownMode>,
+    cache: Option<Arc<dyn CacheStore>>,
+    cache_ttl: Option<Duration>,
+    rate_limiter: Option<RateLimiter>,
+    circuit_breaker: Option<CircuitBreaker>,
+    hedge_delay: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// Returns a new instance of the [`ClientBuilder`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Consumes the [`ClientBuilder`] instance and returns an instance of the [`Client`].
+    /// If no transport was set, a [`ReqwestTransport`] configured by this builder is used.
+    /// # Errors
+    /// Returns [`transport::Error`] of kind [`ErrorKind::Config`](transport::ErrorKind::Config)
+    /// if the underlying [`reqwest::Client`] can't be built (e.g. the user agent isn't a valid header value)
+    /// or if no transport was set and the `reqwest` feature is disabled.
+    /// # Examples
+    #[cfg_attr(feature = "reqwest", doc = "```")]
+    #[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+    /// use scpsl_api::{transport::ErrorKind, Client};
+    ///
+    /// let error = Client::builder().user_agent("bot\n").build().err().unwrap();
+    ///
+    /// assert_eq!(error.kind(), ErrorKind::Config);
+    /// ```
+    pub fn build(self) -> Result<Client, transport::Error> {
+        let transport = match self.transport {
+            Some(transport) => transport,
+            #[cfg(feature = "reqwest")]
+            None => {
+                let mut http = reqwest::Client::builder().default_headers(self.default_headers);
+
+                if let Some(user_agent) = self.user_agent {
+                    http = http.user_agent(user_agent);
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(connect_timeout) = self.connect_timeout {
+                    http = http.connect_timeout(connect_timeout);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(timeout) = self.timeout {
+                    http = http.timeout(timeout);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                for proxy in self.proxies {
+                    http = http.proxy(proxy);
+                }
+
+                let mut transport = ReqwestTransport::from(http.build()?);
+
+                if let Some(max_body_size) = self.max_body_size {
+                    transport = transport.with_max_body_size(max_body_size);
+                }
+
+                Arc::new(transport)
+            }
+            #[cfg(not(feature = "reqwest"))]
+            None => {
+                return Err(transport::Error::new(
+                    transport::ErrorKind::Config,
+                    "no transport was set and the reqwest feature is disabled",
+                ))
+            }
+        };
+
+        Ok(Client {
+            transport,
+            retry: self.retry,
+            max_retry_after: self.max_retry_after.unwrap_or(DEFAULT_MAX_RETRY_AFTER),
+            cooldown: self.cooldown,
+            cooldown_until: Default::default(),
+            cache: self.cache,
+            cache_ttl: self.cache_ttl,
+            rate_limiter: self.rate_limiter,
+            circuit_breaker: self.circuit_breaker,
+            hedge_delay: self.hedge_delay,
+        })
+    }
+
+    /// Sets the [`reqwest::Client`] to perform requests through (see [`Client::with_http_client`]).
+    /// Options configuring the underlying client (e.g. [`ClientBuilder::timeout`]) are ignored if it is set.
+    #[cfg(feature = "reqwest")]
+    pub fn http_client(self, value: reqwest::Client) -> Self {
+        self.transport(ReqwestTransport::from(value))
+    }
+
+    /// Sets the transport to perform requests through (see [`Client::with_transport`]).
+    /// Options configuring the underlying client (e.g. [`ClientBuilder::timeout`]) are ignored if it is set.
+    pub fn transport<T: HttpTransport + 'static>(mut self, value: T) -> Self {
+        self.transport = Some(Arc::new(value));
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    /// Not available on `wasm32`.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.connect_timeout = Some(value);
+        self
+    }
+
+    /// Sets the timeout for a whole request, from connecting until the response body is read.
+    /// Can be overridden for the `serverinfo` request with [`RequestParametersBuilder::timeout`](crate::server_info::RequestParametersBuilder::timeout).
+    /// Not available on `wasm32`.
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .user_agent("my-status-bot/1.0 (+https://example.com)")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "reqwest")]
+    pub fn user_agent<V: Into<String>>(mut self, value: V) -> Self {
+        self.user_agent = Some(value.into());
+        self
+    }
+
+    /// Sets the headers sent with every request, replacing previously set ones.
+    /// The `User-Agent` set with [`ClientBuilder::user_agent`] takes precedence over the one in `value`.
+    #[cfg(feature = "reqwest")]
+    pub fn default_headers(mut self, value: HeaderMap) -> Self {
+        self.default_headers = value;
+        self
+    }
+
+    /// Sets the maximum size of response bodies in bytes, larger ones fail with
+    /// [`ErrorKind::BodyTooLarge`](transport::ErrorKind::BodyTooLarge) without being downloaded entirely.
+    /// Ignored if a custom transport is set, see `with_max_body_size` of the built-in transports instead.
+    #[cfg(feature = "reqwest")]
+    pub fn max_body_size(mut self, value: u64) -> Self {
+        self.max_body_size = Some(value);
+        self
+    }
+
+    /// Adds a proxy to perform requests through, can be called multiple times.
+    /// `http`, `https` and (with the `socks` feature) `socks5` proxies are supported,
+    /// use [`reqwest::Proxy::basic_auth`] for proxies requiring authentication.
+    /// Not available on `wasm32`.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::Client;
+    ///
+    /// let proxy = reqwest::Proxy::all("http://proxy.example.com:3128")
+    ///     .unwrap()
+    ///     .basic_auth("user", "password");
+    /// let client = Client::builder().proxy(proxy).build().unwrap();
+    /// ```
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn proxy(mut self, value: reqwest::Proxy) -> Self {
+        self.proxies.push(value);
+        self
+    }
+
+    /// Enables retrying failed requests made by the client according to the given policy,
+    /// e.g. [`ExponentialBackoff`](crate::retry::ExponentialBackoff) or a boxed custom [`RetryPolicy`].
+    /// Ip address and JSON parse errors are never retried.
+    /// Rate limited `serverinfo` requests are retried if the policy allows retrying a 429 response,
+    /// waiting at least as long as the `Retry-After` header asks (up to [`ClientBuilder::max_retry_after`]).
+    pub fn retry<P: RetryPolicy + 'static>(mut self, value: P) -> Self {
+        self.retry = Some(Arc::new(value));
+        self
+    }
+
+    /// Sets the longest wait the `Retry-After` header of a rate limited response can enforce,
+    /// 5 minutes by default. Longer requested waits are shortened to `value`.
+    pub fn max_retry_after(mut self, value: Duration) -> Self {
+        self.max_retry_after = Some(value);
+        self
+    }
+
+    /// Enables gating `serverinfo` requests by the cooldown returned with the previous successful response.
+    /// The cooldown is shared between clones of the client, but concurrent requests aren't serialized.
+    /// # Examples
+    #[cfg_attr(feature = "reqwest", doc = "```")]
+    #[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+    /// use scpsl_api::{Client, CooldownMode};
+    ///
+    /// let client = Client::builder().cooldown(CooldownMode::Wait).build().unwrap();
+    /// ```
+    pub fn cooldown(mut self, value: CooldownMode) -> Self {
+        self.cooldown = Some(value);
+        self
+    }
+
+    /// Enables serving repeated `serverinfo` requests with the same parameters from the `value` cache
+    /// (see [`crate::cache`]). The cache is shared between clones of the client.
+    pub fn cache<C: CacheStore + 'static>(mut self, value: C) -> Self {
+        self.cache = Some(Arc::new(value));
+        self
+    }
+
+    /// Sets for how long successful responses are cached, by default the cooldown returned with them.
+    pub fn cache_ttl(mut self, value: Duration) -> Self {
+        self.cache_ttl = Some(value);
+        self
+    }
+
+    /// Limits requests (including retries) made by the client and its clones with `value`,
+    /// which can also be shared with other clients.
+    pub fn rate_limiter(mut self, value: RateLimiter) -> Self {
+        self.rate_limiter = Some(value);
+        self
+    }
+
+    /// Makes requests made by the client and its clones fail locally with
+    /// [`ErrorKind::CircuitOpen`](crate::transport::ErrorKind::CircuitOpen) while `value` is open.
+    /// The circuit breaker can also be shared with other clients.
+    pub fn circuit_breaker(mut self, value: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(value);
+        self
+    }
+
+    /// Enables hedging: if a request doesn't complete in `value`, a second identical request is sent
+    /// and the response of whichever completes first is used, cancelling the other one.
+    /// The hedged request is skipped if the [`ClientBuilder::rate_limiter`] budget is exhausted.
+    pub fn hedge(mut self, value: Duration) -> Self {
+        self.hedge_delay = Some(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, CooldownMode};
+    use crate::{
+        rate_limit::RateLimiter,
+        server_info::{Error, ParseError, RequestParameters, ValidationError},
+        transport::{self, HttpResponse, HttpTransport},
+    };
+    use async_trait::async_trait;
+    use std::{
+        future::poll_fn,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::Poll,
+        time::{Duration, Instant},
+    };
+    use url::Url;
+
+    /// A transport answering every request with the same body.
+    struct StubTransport(&'static str);
+
+    #[async_trait]
+    impl HttpTransport for StubTransport {
+        async fn get(&self, _url: &Url) -> Result<HttpResponse, transport::Error> {
+            Ok(HttpResponse::new(200, Vec::new(), self.0.into()))
+        }
+    }
+
+    /// A transport yielding to the executor for `delay` before answering with the `body`.
+    struct SlowTransport {
+        delay: Duration,
+        body: &'static str,
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for SlowTransport {
+        async fn get(&self, _url: &Url) -> Result<HttpResponse, transport::Error> {
+            let start = Instant::now();
+
+            self.requests.fetch_add(1, Ordering::SeqCst);
+
+            while start.elapsed() < self.delay {
+                let mut yielded = false;
+
+                poll_fn(|cx| {
+                    if yielded {
+                        return Poll::Ready(());
+                    }
+
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+
+                    Poll::Pending
+                })
+                .await;
+            }
+
+            Ok(HttpResponse::new(200, Vec::new(), self.body.into()))
+        }
+    }
+
+    fn parameters() -> RequestParameters {
+        RequestParameters::builder()
+            .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn huge_cooldown_is_rejected() {
+        let body = r#"{"Success":true,"Cooldown":18446744073709551615,"Servers":[]}"#;
+
+        for cooldown in [None, Some(CooldownMode::Wait), Some(CooldownMode::Error)] {
+            let mut builder = Client::builder().transport(StubTransport(body));
+
+            if let Some(cooldown) = cooldown {
+                builder = builder.cooldown(cooldown);
+            }
+
+            let client = builder.build().unwrap();
+            let error = client.get_server_info(&parameters()).await.err().unwrap();
+
+            assert!(matches!(
+                error,
+                Error::ParseError(ParseError::ValidationError(
+                    ValidationError::CooldownOutOfRange(u64::MAX)
+                ))
+            ));
+            assert_eq!(client.cooldown_remaining(), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn cooldown_is_tracked_with_mode() {
+        let body = r#"{"Success":true,"Cooldown":60,"Servers":[]}"#;
+        let client = Client::with_transport(StubTransport(body));
+
+        client.get_server_info(&parameters()).await.unwrap();
+        assert_eq!(client.cooldown_remaining(), None);
+
+        let client = Client::builder()
+            .transport(StubTransport(body))
+            .cooldown(CooldownMode::Error)
+            .build()
+            .unwrap();
+
+        client.get_server_info(&parameters()).await.unwrap();
+        assert!(client.cooldown_remaining().is_some());
+        assert!(matches!(
+            client.get_server_info(&parameters()).await,
+            Err(Error::CooldownActive { .. })
+        ));
+    }
+
+    #[test]
+    fn waits_without_runtime() {
+        let client = Client::builder()
+            .transport(StubTransport("127.0.0.1"))
+            .rate_limiter(RateLimiter::new(1, Duration::from_millis(20)))
+            .build()
+            .unwrap();
+        let url = Url::parse("https://api.ipify.org").unwrap();
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            pollster::block_on(client.get_ip(url.clone())).unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn hedge_refused_by_rate_limiter() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = Client::builder()
+            .transport(SlowTransport {
+                delay: Duration::from_millis(50),
+                body: r#"{"Success":true,"Cooldown":60,"Servers":[]}"#,
+                requests: requests.clone(),
+            })
+            .rate_limiter(RateLimiter::new(1, Duration::from_secs(60)))
+            .hedge(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        // The whole budget is spent on the first request, so the hedged one is skipped.
+        client.get_server_info(&parameters()).await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
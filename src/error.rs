@@ -0,0 +1,67 @@
+//! This module contains the crate-level error type these can be used
+//! for handling errors of all API requests uniformly with `?`.
+
+#[cfg(feature = "steam")]
+use crate::steam;
+use crate::{ip, server_info, transport};
+
+/// An enum representing any error returned by this crate.
+/// Every module-specific error converts into it, so functions calling several APIs
+/// can return `Result<T, scpsl_api::Error>` and use `?` everywhere.
+/// # Examples
+#[cfg_attr(feature = "reqwest", doc = "```no_run")]
+#[cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+/// use scpsl_api::{server_info::RequestParameters, Client, Error};
+/// use url::Url;
+///
+/// async fn cooldown(client: &Client) -> Result<u64, Error> {
+///     let parameters = RequestParameters::builder()
+///         .url(Url::parse("https://api.scpslgame.com/serverinfo.php")?)
+///         .build();
+///     let response = client.get_server_info(&parameters).await?.into_result()?;
+///
+///     Ok(response.cooldown())
+/// }
+/// ```
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An enum variant representing [`transport::Error`].
+    #[error(transparent)]
+    TransportError(#[from] transport::Error),
+    /// An enum variant representing [`server_info::Error`].
+    #[error(transparent)]
+    ServerInfoError(#[from] server_info::Error),
+    /// An enum variant representing [`server_info::ParseError`].
+    #[error(transparent)]
+    ParseError(#[from] server_info::ParseError),
+    /// An enum variant representing [`server_info::ApiError`].
+    #[error("API error: {0}")]
+    ApiError(#[from] server_info::ApiError),
+    /// An enum variant representing [`ip::Error`].
+    #[error(transparent)]
+    IpError(#[from] ip::Error),
+    /// An enum variant representing [`url::ParseError`].
+    #[error("invalid url: {0}")]
+    UrlError(#[from] url::ParseError),
+    /// An enum variant representing [`steam::Error`].
+    #[cfg(feature = "steam")]
+    #[error(transparent)]
+    SteamError(#[from] steam::Error),
+}
+
+impl Error {
+    /// Returns `true` if the request may succeed when retried later,
+    /// so generic retry wrappers can tell transient failures from fatal ones.
+    /// Delegates to the `is_retryable` method of the inner error, URL and parse errors are fatal.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::TransportError(error) => error.is_retryable(),
+            Error::ServerInfoError(error) => error.is_retryable(),
+            Error::ApiError(error) => error.is_retryable(),
+            Error::IpError(error) => error.is_retryable(),
+            Error::ParseError(_) | Error::UrlError(_) => false,
+            #[cfg(feature = "steam")]
+            Error::SteamError(error) => error.is_retryable(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! This module contains representative (anonymized) API payloads
+//! these can be used for testing code built on top of this crate
+//! without network access.
+//! # Examples
+//! ```
+//! use scpsl_api::fixtures::SERVER_INFO_FULL;
+//!
+//! let value: serde_json::Value = serde_json::from_str(SERVER_INFO_FULL).unwrap();
+//!
+//! assert_eq!(value["Servers"].as_array().unwrap().len(), 2);
+//! ```
+
+/// A successful `serverinfo` response for an account without any servers.
+pub const SERVER_INFO_EMPTY: &str = r#"{"Success":true,"Cooldown":15,"Servers":[]}"#;
+
+/// A successful `serverinfo` response with only the mandatory `ID` and `Port` fields.
+pub const SERVER_INFO_MINIMAL: &str =
+    r#"{"Success":true,"Cooldown":15,"Servers":[{"ID":61234,"Port":7777}]}"#;
+
+/// A successful `serverinfo` response with every optional parameter enabled,
+/// including players with and without nicknames and a markup-heavy `Info`.
+/// # Examples
+/// ```
+/// use scpsl_api::{
+///     fixtures::SERVER_INFO_FULL,
+///     server_info::{parse_with_mode, ParseMode},
+/// };
+///
+/// // Every field of the fixture is known to this crate.
+/// assert!(parse_with_mode(SERVER_INFO_FULL.as_bytes(), ParseMode::Strict).is_ok());
+/// ```
+pub const SERVER_INFO_FULL: &str = r#"{
+    "Success": true,
+    "Cooldown": 15,
+    "Servers": [
+        {
+            "ID": 61234,
+            "Port": 7777,
+            "LastOnline": "2021-07-14",
+            "Players": "3/25",
+            "PlayersList": [
+                {"ID": "76561198000000001@steam", "Nickname": "Player One"},
+                {"ID": "123456789012345678@discord", "Nickname": "Player Two"},
+                {"ID": "76561198000000003@steam"}
+            ],
+            "Info": "PGNvbG9yPSNmZjAwMDA+PHNpemU9NDA+4piFIE15IFNlcnZlciDimIU8L3NpemU+PC9jb2xvcj4KPGI+VmFuaWxsYSs8L2I+IHwgPGk+RVU8L2k+IHwgPGNvbG9yPSM3Mjg5ZGE+ZGlzY29yZC5nZy9leGFtcGxlPC9jb2xvcj4=",
+            "Pastebin": "7wV681fT",
+            "Version": "13.5.1",
+            "FF": false,
+            "WL": false,
+            "Modded": true,
+            "Mods": 4,
+            "Suppress": false,
+            "AutoSuppress": false,
+            "Online": true
+        },
+        {
+            "ID": 61235,
+            "Port": 7778,
+            "LastOnline": "2021-06-30",
+            "Players": "0/20",
+            "PlayersList": [],
+            "Info": "VGVzdCBzZXJ2ZXI=",
+            "Pastebin": "",
+            "Version": "13.5.1",
+            "FF": true,
+            "WL": true,
+            "Modded": false,
+            "Mods": 0,
+            "Suppress": true,
+            "AutoSuppress": false,
+            "Online": false
+        }
+    ]
+}"#;
+
+/// A successful `serverinfo` response where the players list contains bare user ids
+/// (the format used when `nicknames` isn't requested).
+pub const SERVER_INFO_PLAYERS_WITHOUT_NICKNAMES: &str = r#"{
+    "Success": true,
+    "Cooldown": 15,
+    "Servers": [
+        {
+            "ID": 61234,
+            "Port": 7777,
+            "Players": "2/25",
+            "PlayersList": ["76561198000000001@steam", "123456789012345678@discord"]
+        }
+    ]
+}"#;
+
+/// An unsuccessful `serverinfo` response caused by an invalid API key.
+pub const SERVER_INFO_INVALID_KEY: &str = r#"{"Success":false,"Error":"Invalid API key."}"#;
+
+/// An unsuccessful `serverinfo` response caused by the rate limit.
+pub const SERVER_INFO_RATE_LIMITED: &str =
+    r#"{"Success":false,"Error":"Rate limit exceeded, please try again later."}"#;
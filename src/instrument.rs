@@ -0,0 +1,23 @@
+//! `tracing` instrumentation of API requests.
+
+use crate::time::Instant;
+use std::{fmt::Debug, future::Future};
+use tracing::{Instrument, Span};
+
+/// Runs the request `future` inside the `span` and emits an event with its duration and outcome.
+pub(crate) async fn request<T, E, F>(span: Span, future: F) -> Result<T, E>
+where
+    E: Debug,
+    F: Future<Output = Result<T, E>>,
+{
+    let start = Instant::now();
+    let result = future.instrument(span.clone()).await;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    span.in_scope(|| match &result {
+        Ok(_) => tracing::debug!(elapsed_ms, "request succeeded"),
+        Err(error) => tracing::warn!(elapsed_ms, ?error, "request failed"),
+    });
+
+    result
+}
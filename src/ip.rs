@@ -1,33 +1,243 @@
 //! This module contains functionality that can be used for
 //! working with the `ip` API request.
 
+#[cfg(feature = "reqwest")]
+use crate::transport::ReqwestTransport;
+use crate::{
+    time::Instant,
+    transport::{self, HttpTransport},
+};
+#[cfg(feature = "opentelemetry")]
+use opentelemetry::KeyValue;
 use std::{
     net::{AddrParseError, IpAddr},
     str::FromStr,
+    time::Duration,
 };
 use url::Url;
 
 /// An enum representing an error for the `ip` request.
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// An enum variant representing [`AddrParseError`].
-    AddrParseError(AddrParseError),
-    /// An enum variant representing [`reqwest::Error`].
-    ReqwestError(reqwest::Error),
+    #[error("invalid ip response: {0}")]
+    AddrParseError(#[source] AddrParseError),
+    /// An enum variant representing [`transport::Error`].
+    #[error(transparent)]
+    TransportError(#[from] transport::Error),
+}
+
+impl Error {
+    /// Returns `true` if the request may succeed when retried later, i.e. it failed with a transient transport error.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::TransportError(error) => error.is_retryable(),
+            Error::AddrParseError(_) => false,
+        }
+    }
+}
+
+/// A struct representing a parsed API response for the `ip` request.
+#[derive(Clone)]
+pub struct IpResponse {
+    addr: IpAddr,
+    raw_text: String,
+    source_url: Url,
+    latency: Duration,
+    status: u16,
+    attempts: Vec<IpAttempt>,
+}
+
+impl IpResponse {
+    /// Get a reference to the ip response's addr.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Get a reference to the ip response's raw text.
+    pub fn raw_text(&self) -> &str {
+        self.raw_text.as_str()
+    }
+
+    /// Get a reference to the ip response's source url.
+    pub fn source_url(&self) -> &Url {
+        &self.source_url
+    }
+
+    /// Get a reference to the ip response's attempts.
+    /// The last attempt is always the one made to [`IpResponse::source_url`].
+    pub fn attempts(&self) -> &[IpAttempt] {
+        self.attempts.as_slice()
+    }
+
+    /// Get a reference to the ip response's latency, i.e. of the request made to [`IpResponse::source_url`].
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// Get a reference to the ip response's HTTP status, i.e. of the response from [`IpResponse::source_url`].
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+}
+
+/// A struct representing a single request made while detecting the ip.
+#[derive(Clone)]
+pub struct IpAttempt {
+    url: Url,
+    latency: Duration,
+    status: Option<u16>,
+    succeeded: bool,
+}
+
+impl IpAttempt {
+    /// Get a reference to the ip attempt's url.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Get a reference to the ip attempt's latency.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// Get a reference to the ip attempt's HTTP status.
+    /// Returns [`None`] if no response was received.
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// Returns `true` if an ip address was successfully received in this attempt.
+    pub fn succeeded(&self) -> bool {
+        self.succeeded
+    }
+}
+
+/// Returns the attempt and the response without any attempts.
+async fn attempt(
+    transport: &dyn HttpTransport,
+    url: &Url,
+) -> (IpAttempt, Result<IpResponse, Error>) {
+    let start = Instant::now();
+    let mut status = None;
+    let result = match transport.get(url).await {
+        Ok(response) if (500..600).contains(&response.status()) => {
+            status = Some(response.status());
+
+            Err(Error::TransportError(
+                transport::Error::status(response.status()).with_url(url),
+            ))
+        }
+        Ok(response) => {
+            status = Some(response.status());
+
+            let text = String::from_utf8_lossy(response.body()).into_owned();
+
+            match IpAddr::from_str(text.trim()) {
+                Ok(addr) => Ok(IpResponse {
+                    addr,
+                    raw_text: text,
+                    source_url: url.clone(),
+                    latency: start.elapsed(),
+                    status: response.status(),
+                    attempts: Vec::new(),
+                }),
+                Err(error) => Err(Error::AddrParseError(error)),
+            }
+        }
+        Err(error) => Err(Error::TransportError(error.with_url(url))),
+    };
+
+    (
+        IpAttempt {
+            url: url.clone(),
+            latency: start.elapsed(),
+            status,
+            succeeded: result.is_ok(),
+        },
+        result,
+    )
 }
 
 /// Returns current ip.
+/// Leading and trailing whitespace in the response body is ignored.
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client`] to reuse connections.
 /// # Errors
 /// Returns [`Error::AddrParseError`] if there was a returned ip address parse error.
-/// Returns [`Error::ReqwestError`] if there was a [`reqwest::Error`].
-pub async fn get(url: Url) -> Result<IpAddr, Error> {
-    match reqwest::get(url).await {
-        Ok(response) => match response.text().await {
-            Ok(text) => match IpAddr::from_str(text.as_str()) {
-                Ok(ip) => Ok(ip),
-                Err(error) => Err(Error::AddrParseError(error)),
-            },
-            Err(error) => Err(Error::ReqwestError(error)),
-        },
-        Err(error) => Err(Error::ReqwestError(error)),
+/// Returns [`Error::TransportError`] if the request failed or the response status was 5xx.
+#[cfg(feature = "reqwest")]
+pub async fn get(url: Url) -> Result<IpResponse, Error> {
+    get_with_fallbacks(url, &[]).await
+}
+
+/// Returns current ip, trying the `primary` url and then each of the `fallbacks` in order
+/// until one of them succeeds.
+/// The url that answered can be obtained with [`IpResponse::source_url`],
+/// and the failed attempts preceding it with [`IpResponse::attempts`].
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client`] to reuse connections.
+/// # Errors
+/// Returns the error of the last url if none of the urls succeeded. See [`get`].
+#[cfg(feature = "reqwest")]
+pub async fn get_with_fallbacks(primary: Url, fallbacks: &[Url]) -> Result<IpResponse, Error> {
+    fetch(&ReqwestTransport::new(), &primary, fallbacks).await
+}
+
+pub(crate) async fn fetch(
+    transport: &dyn HttpTransport,
+    primary: &Url,
+    fallbacks: &[Url],
+) -> Result<IpResponse, Error> {
+    #[cfg(any(feature = "opentelemetry", feature = "tracing"))]
+    let urls = std::iter::once(primary)
+        .chain(fallbacks)
+        .map(Url::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let future = perform(transport, primary, fallbacks);
+    #[cfg(feature = "metrics")]
+    let future = crate::stats::request("ip", future);
+    #[cfg(feature = "opentelemetry")]
+    let future =
+        crate::telemetry::request("ip", vec![KeyValue::new("url.full", urls.clone())], async {
+            let response = future.await?;
+
+            crate::telemetry::record([KeyValue::new(
+                "http.response.status_code",
+                i64::from(response.status()),
+            )]);
+
+            Ok::<_, Error>(response)
+        });
+    #[cfg(feature = "tracing")]
+    let future = crate::instrument::request(tracing::info_span!("ip", urls = %urls), future);
+
+    future.await
+}
+
+/// Tries the `primary` url and then the `fallbacks` until one of them succeeds.
+async fn perform(
+    transport: &dyn HttpTransport,
+    primary: &Url,
+    fallbacks: &[Url],
+) -> Result<IpResponse, Error> {
+    let mut attempts = Vec::with_capacity(fallbacks.len() + 1);
+    let (current_attempt, mut result) = attempt(transport, primary).await;
+
+    attempts.push(current_attempt);
+
+    for url in fallbacks {
+        if result.is_ok() {
+            break;
+        }
+
+        let (current_attempt, current_result) = attempt(transport, url).await;
+
+        attempts.push(current_attempt);
+        result = current_result;
     }
+
+    result.map(|response| IpResponse {
+        attempts,
+        ..response
+    })
 }
@@ -1,7 +1,48 @@
 //! This crate is a API helper for SCP: Secret Laboratory game.
 //! Official API reference can be found [here](https://api.scpslgame.com).
+//!
+//! Requests are performed through a [`transport::HttpTransport`], by default the
+//! [`reqwest`](https://docs.rs/reqwest)-based one enabled by the `reqwest` feature.
+//! TLS for the `reqwest` and `ureq` transports is provided either by the platform library
+//! with the `native-tls` feature (enabled by default) or by `rustls` with the `rustls-tls` feature,
+//! which allows building fully static binaries without OpenSSL:
+//!
+//! ```toml
+//! scpsl-api = { version = "0.1.0-alpha.10", default-features = false, features = ["chrono", "reqwest", "rustls-tls"] }
+//! ```
+//!
+//! Requests can be observed with the `tracing`, `opentelemetry` and `metrics` features.
+//! The latter emits the following metrics labeled with the `endpoint` (`serverinfo` or `ip`):
+//! - `scpsl_api_requests_total` counter;
+//! - `scpsl_api_errors_total` counter, additionally labeled with the error `kind`;
+//! - `scpsl_api_rate_limited_total` counter of responses rejected because of the rate limit;
+//! - `scpsl_api_request_duration_seconds` histogram.
+//!
+//! The crate can be compiled for `wasm32-unknown-unknown`, in which case requests
+//! are performed with the browser's `fetch` and timeouts can't be configured.
 
 #![warn(missing_docs)]
 
+pub mod cache;
+pub mod circuit_breaker;
+mod client;
+mod error;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+#[cfg(feature = "tracing")]
+mod instrument;
 pub mod ip;
+pub mod rate_limit;
+pub mod retry;
 pub mod server_info;
+#[cfg(feature = "metrics")]
+mod stats;
+#[cfg(feature = "steam")]
+pub mod steam;
+#[cfg(feature = "opentelemetry")]
+mod telemetry;
+mod time;
+pub mod transport;
+
+pub use client::{Client, ClientBuilder, CooldownMode};
+pub use error::Error;
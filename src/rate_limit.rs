@@ -0,0 +1,102 @@
+//! This module contains the [`RateLimiter`] struct these can be used
+//! for sharing one request budget between all tasks using a [`Client`](crate::Client).
+//! # Examples
+#![cfg_attr(feature = "reqwest", doc = "```")]
+#![cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
+//! use scpsl_api::{rate_limit::RateLimiter, Client};
+//! use std::time::Duration;
+//!
+//! // At most 10 requests per minute, shared by both clients and their clones.
+//! let limiter = RateLimiter::new(10, Duration::from_secs(60));
+//! let first = Client::builder().rate_limiter(limiter.clone()).build().unwrap();
+//! let second = Client::builder().rate_limiter(limiter).build().unwrap();
+//! ```
+
+use crate::time::{self, Instant};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A struct representing a rate limiter allowing `burst` requests at once
+/// and then one request every `period / burst`.
+/// Cloning is cheap and clones share the same budget.
+#[derive(Clone)]
+pub struct RateLimiter {
+    burst: u32,
+    period: Duration,
+    interval: Duration,
+    /// The time at which the budget will be full again.
+    full_at: Arc<Mutex<Option<Instant>>>,
+}
+
+impl RateLimiter {
+    /// Returns a new instance of the [`RateLimiter`] allowing `burst` requests per `period`.
+    /// # Panics
+    /// Panics if `burst` is zero.
+    pub fn new(burst: u32, period: Duration) -> Self {
+        assert!(burst != 0, "burst must not be zero");
+
+        Self {
+            burst,
+            period,
+            interval: period / burst,
+            full_at: Default::default(),
+        }
+    }
+
+    /// Get a reference to the rate limiter's burst.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Get a reference to the rate limiter's period.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Reserves a request from the budget and returns the time to wait before performing it.
+    /// Reservations are served in order, so waiting tasks don't starve each other.
+    pub fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut full_at = self.full_at.lock().unwrap();
+        let start = match *full_at {
+            Some(full_at) if full_at > now => full_at,
+            _ => now,
+        };
+        let wait = (start + self.interval).saturating_duration_since(now + self.period);
+
+        *full_at = Some(start + self.interval);
+
+        wait
+    }
+
+    /// Reserves a request from the budget only if it can be performed right away.
+    /// Returns `false` if the budget is exhausted.
+    pub fn try_acquire(&self) -> bool {
+        let now = Instant::now();
+        let mut full_at = self.full_at.lock().unwrap();
+        let start = match *full_at {
+            Some(full_at) if full_at > now => full_at,
+            _ => now,
+        };
+
+        if start + self.interval > now + self.period {
+            return false;
+        }
+
+        *full_at = Some(start + self.interval);
+
+        true
+    }
+
+    /// Waits until a request fits into the budget.
+    /// Works with any executor, a [`Client`](crate::Client) waits with the timer of its transport instead.
+    pub async fn acquire(&self) {
+        let wait = self.reserve();
+
+        if !wait.is_zero() {
+            time::sleep(wait).await;
+        }
+    }
+}
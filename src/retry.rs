@@ -0,0 +1,181 @@
+//! This module contains functionality that can be used for
+//! retrying failed API requests.
+
+use crate::transport::{Error, ErrorKind};
+use std::{convert::TryFrom, time::Duration};
+
+/// A trait deciding whether and when a failed request should be retried.
+/// # Examples
+/// ```
+/// use scpsl_api::{retry::RetryPolicy, transport::Error};
+/// use std::time::Duration;
+///
+/// /// Retries timeouts up to 10 times every second and never retries anything else.
+/// struct RetryTimeouts;
+///
+/// impl RetryPolicy for RetryTimeouts {
+///     fn retry_after(&self, error: &Error, attempt: u32) -> Option<Duration> {
+///         if error.is_timeout() && attempt < 10 {
+///             Some(Duration::from_secs(1))
+///         } else {
+///             None
+///         }
+///     }
+/// }
+/// ```
+pub trait RetryPolicy: Send + Sync {
+    /// Returns the delay to wait before retrying the request that failed with `error`
+    /// on the attempt number `attempt` (starting from 1), or [`None`] if it shouldn't be retried.
+    fn retry_after(&self, error: &Error, attempt: u32) -> Option<Duration>;
+}
+
+impl<P: RetryPolicy + ?Sized> RetryPolicy for Box<P> {
+    fn retry_after(&self, error: &Error, attempt: u32) -> Option<Duration> {
+        (**self).retry_after(error, attempt)
+    }
+}
+
+/// A struct representing a retry configuration with exponentially growing delays between attempts.
+/// Only transient failures (timeouts, connection errors, 429 and 5xx responses) are retried.
+#[derive(Clone, Copy)]
+pub struct ExponentialBackoff {
+    max_attempts: u32,
+    base_delay: Duration,
+    factor: f64,
+    max_delay: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Returns a new instance of the [`ExponentialBackoff`].
+    /// `max_attempts` includes the first attempt, so 1 disables retries.
+    /// The delay before the n-th retry is `base_delay * factor^(n - 1)`, capped at 5 minutes
+    /// (see [`ExponentialBackoff::with_max_delay`]).
+    /// # Panics
+    /// Panics if `factor` isn't finite or is less than 1.
+    pub fn new(max_attempts: u32, base_delay: Duration, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor >= 1.0,
+            "factor must be finite and at least 1"
+        );
+
+        Self {
+            max_attempts,
+            base_delay,
+            factor,
+            max_delay: Duration::from_secs(300),
+        }
+    }
+
+    /// Returns the backoff with delays capped at `value`.
+    pub fn with_max_delay(mut self, value: Duration) -> Self {
+        self.max_delay = value;
+        self
+    }
+
+    /// Get a reference to the exponential backoff's max attempts.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Get a reference to the exponential backoff's base delay.
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    /// Get a reference to the exponential backoff's factor.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Get a reference to the exponential backoff's max delay.
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Returns the delay to wait after the failed attempt number `attempt` (starting from 1).
+    /// # Examples
+    /// ```
+    /// use scpsl_api::retry::ExponentialBackoff;
+    /// use std::time::Duration;
+    ///
+    /// let backoff = ExponentialBackoff::new(4, Duration::from_millis(100), 2.0);
+    ///
+    /// assert_eq!(backoff.delay(1), Duration::from_millis(100));
+    /// assert_eq!(backoff.delay(3), Duration::from_millis(400));
+    /// assert_eq!(backoff.delay(u32::MAX), backoff.max_delay());
+    /// ```
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        // Bounded so that a zero base delay never meets an infinite multiplier.
+        let multiplier = self.factor.powi(exponent).min(f64::from(u32::MAX));
+
+        Duration::try_from_secs_f64(self.base_delay.as_secs_f64() * multiplier)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry_after(&self, error: &Error, attempt: u32) -> Option<Duration> {
+        if attempt < self.max_attempts && is_transient(error) {
+            Some(self.delay(attempt))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ExponentialBackoff {
+    /// Returns 3 attempts with 500 ms base delay and factor 2.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(500), 2.0)
+    }
+}
+
+/// Returns `true` if the request that failed with `error` may succeed when retried,
+/// i.e. it is a timeout, connection, request or body error, or a 429 or 5xx response.
+pub fn is_transient(error: &Error) -> bool {
+    match error.kind() {
+        ErrorKind::Timeout | ErrorKind::Connect | ErrorKind::Request | ErrorKind::Body => true,
+        ErrorKind::Status(status) => status == 429 || (500..600).contains(&status),
+        ErrorKind::BodyTooLarge(_)
+        | ErrorKind::CircuitOpen
+        | ErrorKind::Config
+        | ErrorKind::Other => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExponentialBackoff;
+    use std::time::Duration;
+
+    #[test]
+    fn delay_saturates() {
+        let backoff = ExponentialBackoff::new(u32::MAX, Duration::from_millis(500), 2.0);
+
+        assert_eq!(backoff.delay(70), backoff.max_delay());
+        assert_eq!(backoff.delay(u32::MAX), backoff.max_delay());
+
+        let backoff =
+            ExponentialBackoff::new(u32::MAX, Duration::MAX, 1.5).with_max_delay(Duration::MAX);
+
+        assert_eq!(backoff.delay(2), Duration::MAX);
+
+        let backoff = ExponentialBackoff::new(u32::MAX, Duration::ZERO, 2.0);
+
+        assert_eq!(backoff.delay(u32::MAX), Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_factor() {
+        ExponentialBackoff::new(3, Duration::from_millis(500), -2.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_factor() {
+        ExponentialBackoff::new(3, Duration::from_millis(500), f64::NAN);
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt::{self, Display, Formatter};
+
+/// An enum representing a known error message returned by the `serverinfo` API.
+/// Returned by [`ErrorResponse::api_error`](super::ErrorResponse::api_error).
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ApiError {
+    /// The API key is invalid or doesn't belong to the account.
+    InvalidApiKey,
+    /// The IP address the request was sent from isn't verified.
+    IpNotVerified,
+    /// The rate limit was exceeded.
+    RateLimitExceeded,
+    /// An error message not recognized by this crate.
+    Other(String),
+}
+
+impl ApiError {
+    /// Returns a new instance of the [`ApiError`] recognized from the error `message`.
+    /// Messages are matched case-insensitively, the more specific phrases first, so e.g.
+    /// "Rate limit exceeded for this API key" is [`ApiError::RateLimitExceeded`].
+    /// Unknown messages, including ones about missing parameters, are kept in [`ApiError::Other`].
+    /// # Examples
+    /// ```
+    /// use scpsl_api::server_info::ApiError;
+    ///
+    /// assert_eq!(ApiError::parse("Invalid API key"), ApiError::InvalidApiKey);
+    /// assert_eq!(ApiError::parse("Rate limit exceeded"), ApiError::RateLimitExceeded);
+    /// assert_eq!(ApiError::parse("Oops"), ApiError::Other("Oops".to_string()));
+    /// ```
+    pub fn parse(message: &str) -> Self {
+        let lowercase = message.to_lowercase();
+        let has_word = |word: &str| {
+            lowercase
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|part| part == word)
+        };
+
+        if lowercase.contains("rate limit") {
+            ApiError::RateLimitExceeded
+        } else if has_word("missing") {
+            ApiError::Other(message.to_string())
+        } else if has_word("ip") && lowercase.contains("not verified") {
+            ApiError::IpNotVerified
+        } else if lowercase.contains("api key") {
+            ApiError::InvalidApiKey
+        } else {
+            ApiError::Other(message.to_string())
+        }
+    }
+
+    /// Returns `true` if the request may succeed when retried later, i.e. the rate limit was exceeded.
+    pub fn is_retryable(&self) -> bool {
+        *self == ApiError::RateLimitExceeded
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::InvalidApiKey => write!(f, "invalid API key"),
+            ApiError::IpNotVerified => write!(f, "IP address not verified"),
+            ApiError::RateLimitExceeded => write!(f, "rate limit exceeded"),
+            ApiError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiError;
+
+    #[test]
+    fn parse() {
+        let other = |message: &str| ApiError::Other(message.to_string());
+        let cases = [
+            ("Invalid API key", ApiError::InvalidApiKey),
+            ("invalid api key", ApiError::InvalidApiKey),
+            (
+                "API key doesn't belong to this account",
+                ApiError::InvalidApiKey,
+            ),
+            ("IP address not verified", ApiError::IpNotVerified),
+            ("Your IP is not verified", ApiError::IpNotVerified),
+            ("Rate limit exceeded", ApiError::RateLimitExceeded),
+            (
+                "Rate limit exceeded for this API key",
+                ApiError::RateLimitExceeded,
+            ),
+            (
+                "Missing API key parameter",
+                other("Missing API key parameter"),
+            ),
+            ("Missing ID parameter", other("Missing ID parameter")),
+            (
+                "Multiple servers not verified",
+                other("Multiple servers not verified"),
+            ),
+            ("", other("")),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(ApiError::parse(message), expected, "{:?}", message);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt::{self, Debug, Formatter};
+use zeroize::Zeroize;
+
+/// A struct representing an API key for the `serverinfo` request.
+/// The key is zeroized on drop and intentionally implements neither `Display` nor a revealing [`Debug`],
+/// so it can't leak into logs through derived traits or format strings.
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::ApiKey;
+///
+/// let key = ApiKey::new("secret");
+///
+/// assert_eq!(format!("{:?}", key), "ApiKey(***)");
+/// assert_eq!(key.expose_secret(), "secret");
+/// ```
+#[derive(Clone)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    /// Returns a new instance of the [`ApiKey`].
+    pub fn new<V: Into<String>>(value: V) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the key itself. Don't log or display the returned value.
+    pub fn expose_secret(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Debug for ApiKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiKey(***)")
+    }
+}
+
+impl Drop for ApiKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
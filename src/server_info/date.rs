@@ -0,0 +1,221 @@
+/// A struct representing a validated date in the `YYYY-MM-DD` format.
+/// Returned by [`ServerInfo::last_online`](super::ServerInfo::last_online) when the `chrono` feature is disabled.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateString(String);
+
+impl DateString {
+    /// Returns a new instance of the [`DateString`] if `value` is a valid date in the `YYYY-MM-DD` format.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::server_info::DateString;
+    ///
+    /// assert_eq!(DateString::parse("2021-07-14").unwrap().month(), 7);
+    /// assert!(DateString::parse("2021-02-29").is_none());
+    /// assert!(DateString::parse("14.07.2021").is_none());
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+
+        if bytes.len() != 10
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || !bytes
+                .iter()
+                .enumerate()
+                .all(|(index, byte)| index == 4 || index == 7 || byte.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let date = Self(value.to_string());
+        let days_in_month = match date.month() {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year(date.year()) => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        if (1..=days_in_month).contains(&date.day()) {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// Get a reference to the date string's value.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the date's year.
+    pub fn year(&self) -> i32 {
+        self.0[0..4].parse().unwrap()
+    }
+
+    /// Returns the date's month starting from 1.
+    pub fn month(&self) -> u32 {
+        self.0[5..7].parse().unwrap()
+    }
+
+    /// Returns the date's day of month starting from 1.
+    pub fn day(&self) -> u32 {
+        self.0[8..10].parse().unwrap()
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the UTC date in the `YYYY-MM-DD` format of a `LastOnline` value which is either a date
+/// (`2021-07-14` or `20210714`), a date with time (e.g. `2021-07-14T12:00:00Z`, `2021-07-14 12:00:00`
+/// or `2021-07-14T01:00:00+03:00`, converted to UTC) or a unix timestamp in seconds.
+/// Eight digits are always read as a date, as such timestamps would be in the early 1970s.
+/// Times without an offset are assumed to be in UTC. The returned date isn't validated.
+pub(super) fn last_online_date(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        if value.len() == 8 {
+            return Some(format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..]));
+        }
+
+        let timestamp: i64 = value.parse().ok()?;
+        let (year, month, day) = civil_from_days(timestamp / 86_400);
+
+        return Some(format!("{:04}-{:02}-{:02}", year, month, day));
+    }
+
+    match value.as_bytes().get(10) {
+        None => Some(value.to_string()),
+        Some(b'T') | Some(b' ') => utc_date(&value[..10], &value[11..]),
+        Some(_) => None,
+    }
+}
+
+/// Returns the UTC date of the `date` at the `time` with an optional offset (`Z`, `+HH:MM`, `-HHMM` or `+HH`).
+fn utc_date(date: &str, time: &str) -> Option<String> {
+    let (time, offset) = match time.find(['+', '-']) {
+        Some(index) => (&time[..index], parse_offset(&time[index..])?),
+        None => (time.trim_end_matches(['Z', 'z']), 0),
+    };
+
+    if offset == 0 {
+        return Some(date.to_string());
+    }
+
+    let hours: i64 = parse_digits(time.get(0..2)?)?;
+    let minutes: i64 = parse_digits(time.get(3..5)?)?;
+
+    if time.as_bytes()[2] != b':' || hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    let day_shift = (hours * 60 + minutes - offset).div_euclid(24 * 60);
+
+    if day_shift == 0 {
+        return Some(date.to_string());
+    }
+
+    let (year, month, day) = (
+        parse_digits(date.get(0..4)?)?,
+        parse_digits(date.get(5..7)?)?,
+        parse_digits(date.get(8..10)?)?,
+    );
+    let (year, month, day) = civil_from_days(days_from_civil(year, month, day) + day_shift);
+
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+/// Returns the offset in minutes east of UTC.
+fn parse_offset(offset: &str) -> Option<i64> {
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits = offset[1..].replace(':', "");
+    let (hours, minutes): (i64, i64) = match digits.len() {
+        2 => (parse_digits(&digits)?, 0),
+        4 => (parse_digits(&digits[..2])?, parse_digits(&digits[2..])?),
+        _ => return None,
+    };
+
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Parses a number consisting of ASCII digits only, unlike [`str::parse`] rejecting signs.
+fn parse_digits<T: std::str::FromStr>(value: &str) -> Option<T> {
+    if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+/// Returns the number of days since the unix epoch of the civil date,
+/// see http://howardhinnant.github.io/date_algorithms.html.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the civil date of the number of days since the unix epoch,
+/// see http://howardhinnant.github.io/date_algorithms.html.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::last_online_date;
+
+    #[test]
+    fn last_online_dates() {
+        let cases = [
+            ("2021-07-14", Some("2021-07-14")),
+            (" 2021-07-14 ", Some("2021-07-14")),
+            ("20210714", Some("2021-07-14")),
+            ("2021-07-14T12:00:00Z", Some("2021-07-14")),
+            ("2021-07-14 12:00:00", Some("2021-07-14")),
+            ("2021-07-14T12:00:00.123+03:00", Some("2021-07-14")),
+            ("2021-07-14T01:00:00+03:00", Some("2021-07-13")),
+            ("2021-07-14T23:30:00-01:00", Some("2021-07-15")),
+            ("2021-12-31T22:00:00-0300", Some("2022-01-01")),
+            ("2021-03-01T00:00:00+01", Some("2021-02-28")),
+            ("0", Some("1970-01-01")),
+            ("1626264000", Some("2021-07-14")),
+            ("2021-07-14X12:00:00", None),
+            ("2021-07-14T01:00:00+3", None),
+            ("2021-07-14T01:00:00+25:00", None),
+            ("2021-07-14Tab:00:00+03:00", None),
+            ("99999999999999999999", None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(last_online_date(value).as_deref(), expected, "{:?}", value);
+        }
+    }
+}
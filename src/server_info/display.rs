@@ -0,0 +1,131 @@
+use super::ServerInfo;
+
+/// Removes Unity rich text tags (e.g. `<color=#ff0000>`, `</size>`) from `text`.
+/// Text in angle brackets is treated as a tag only if it starts with a letter
+/// (optionally preceded by `/`), so strings like `<3` are left untouched.
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::strip_markup;
+///
+/// assert_eq!(
+///     strip_markup("<color=#ff0000><b>My</b> Server</color> <3"),
+///     "My Server <3"
+/// );
+/// ```
+pub fn strip_markup(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        result.push_str(&rest[..start]);
+
+        let candidate = &rest[start + 1..];
+        let name = candidate.strip_prefix('/').unwrap_or(candidate);
+        let end = candidate.find(['>', '<']);
+
+        match end {
+            Some(end)
+                if candidate[end..].starts_with('>')
+                    && name.starts_with(|c: char| c.is_ascii_alphabetic()) =>
+            {
+                rest = &candidate[end + 1..];
+            }
+            _ => {
+                result.push('<');
+                rest = candidate;
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+fn is_decorative(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_control()
+        || (!c.is_ascii() && !c.is_alphanumeric())
+        || "-=_~*#|:".contains(c)
+}
+
+impl ServerInfo {
+    /// Returns the server's name: the first non-empty line of the server's info
+    /// with markup removed (see [`strip_markup`]) and decorative characters
+    /// (symbols, emoji, `-=_~*#|:`) trimmed from both ends.
+    /// If the name is longer than `max_length` characters, it is truncated
+    /// to `max_length` characters including a trailing `…` (empty if `max_length` is 0).
+    /// # Examples
+    /// ```
+    /// # use scpsl_api::server_info::ServerInfo;
+    /// let mut server = ServerInfo::default();
+    ///
+    /// *server.info_mut() = Some("<color=red>★ My Server ★</color>\nRules: ...".to_string());
+    ///
+    /// assert_eq!(server.display_name(32).as_deref(), Some("My Server"));
+    /// assert_eq!(server.display_name(5).as_deref(), Some("My S…"));
+    /// ```
+    pub fn display_name(&self, max_length: usize) -> Option<String> {
+        let stripped = strip_markup(self.info.as_ref()?);
+        let name = stripped
+            .lines()
+            .map(|line| line.trim_matches(is_decorative))
+            .find(|line| !line.is_empty())?;
+
+        if max_length == 0 {
+            Some(String::new())
+        } else if name.chars().count() > max_length {
+            let mut truncated: String = name
+                .chars()
+                .take(max_length.saturating_sub(1))
+                .collect::<String>()
+                .trim_end()
+                .to_string();
+
+            truncated.push('…');
+            Some(truncated)
+        } else {
+            Some(name.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::server_info::ServerInfo;
+
+    fn server(info: Option<&str>) -> ServerInfo {
+        ServerInfo {
+            info: info.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn display_name_lengths() {
+        let server = server(Some("★ My Server ★"));
+        let cases = [
+            (0, ""),
+            (1, "…"),
+            (2, "M…"),
+            // The space before the ellipsis is trimmed.
+            (4, "My…"),
+            (8, "My Serv…"),
+            (9, "My Server"),
+            (usize::MAX, "My Server"),
+        ];
+
+        for (max_length, expected) in cases {
+            let name = server.display_name(max_length).unwrap();
+
+            assert_eq!(name, expected, "{}", max_length);
+            assert!(name.chars().count() <= max_length, "{}", max_length);
+        }
+    }
+
+    #[test]
+    fn display_name_missing() {
+        assert_eq!(server(None).display_name(32), None);
+        assert_eq!(server(Some("")).display_name(32), None);
+        assert_eq!(server(Some("★ ~~~ ★\n\n")).display_name(0), None);
+    }
+}
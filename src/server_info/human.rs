@@ -0,0 +1,61 @@
+#[cfg(feature = "chrono")]
+use super::ServerInfo;
+use super::SuccessResponse;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "chrono")]
+fn format_days_ago(days: i64) -> String {
+    match days {
+        i64::MIN..=0 => "today".to_string(),
+        1 => "yesterday".to_string(),
+        2..=13 => format!("{} days ago", days),
+        14..=59 => format!("{} weeks ago", days / 7),
+        60..=729 => format!("{} months ago", days / 30),
+        _ => format!("{} years ago", days / 365),
+    }
+}
+
+fn format_seconds(seconds: u64) -> String {
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let mut parts = Vec::new();
+
+    if hours != 0 {
+        parts.push(format!("{}h", hours));
+    }
+    if minutes != 0 {
+        parts.push(format!("{}m", minutes));
+    }
+    if seconds != 0 || parts.is_empty() {
+        parts.push(format!("{}s", seconds));
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(feature = "chrono")]
+impl ServerInfo {
+    /// Returns the server's last online date relative to `now` in a human-friendly form
+    /// (`"today"`, `"yesterday"`, `"3 days ago"`, `"2 weeks ago"` and so on).
+    /// Dates after `now` are reported as `"today"`.
+    /// # Examples
+    /// ```
+    /// # use scpsl_api::server_info::ServerInfo;
+    /// # let server = ServerInfo::default();
+    /// if let Some(last_online) = server.last_online_human(chrono::Utc::now()) {
+    ///     println!("Last online {}", last_online);
+    /// }
+    /// ```
+    pub fn last_online_human(&self, now: DateTime<Utc>) -> Option<String> {
+        self.last_online.map(|last_online| {
+            format_days_ago((now.date_naive() - last_online.naive_utc()).num_days())
+        })
+    }
+}
+
+impl SuccessResponse {
+    /// Returns the success response's cooldown in a human-friendly form (e.g. `"1m 30s"`).
+    pub fn cooldown_human(&self) -> String {
+        format_seconds(self.cooldown)
+    }
+}
@@ -1,7 +1,8 @@
 //! This module contains structs and functions these can be used
 //! for working with the `serverinfo` API request.
 //! # Examples
-//! ```
+#![cfg_attr(feature = "reqwest", doc = "```no_run")]
+#![cfg_attr(not(feature = "reqwest"), doc = "```ignore")]
 //! use scpsl_api::server_info::{get, RequestParameters, Response};
 //! use std::env::var;
 //! use url::Url;
@@ -34,16 +35,225 @@
 //! }
 //! ```
 
+mod api_error;
+mod api_key;
+mod date;
+mod display;
+mod human;
+mod players;
 #[cfg(not(feature = "raw"))]
-mod raw;
+pub(crate) mod raw;
 #[cfg(feature = "raw")]
 pub mod raw;
 
+#[cfg(feature = "steam")]
+use crate::steam::SteamProfile;
+use crate::transport::{self, Timings};
+#[cfg(feature = "reqwest")]
+use crate::{
+    time::Instant,
+    transport::{HttpResponse, ReqwestTransport},
+};
+pub use api_error::ApiError;
+pub use api_key::ApiKey;
+#[cfg(feature = "chrono")]
+#[allow(deprecated)]
 use chrono::{Date, NaiveDate, Utc};
+pub use date::DateString;
+pub use display::strip_markup;
+pub use players::PlayersListIssue;
 use raw::*;
-use reqwest::Error;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+    time::Duration,
+};
 use url::Url;
 
+/// An enum representing an error for the `serverinfo` request.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An enum variant representing [`transport::Error`].
+    #[error(transparent)]
+    TransportError(#[from] transport::Error),
+    /// An enum variant representing [`serde_json::Error`] together with the path
+    /// of the field it occurred at (see [`serde_path_to_error::Error::path`]).
+    #[error("invalid serverinfo response: {0}")]
+    JsonError(#[source] serde_path_to_error::Error<serde_json::Error>),
+    /// An enum variant representing [`ParseError`].
+    #[error("malformed serverinfo response: {0}")]
+    ParseError(#[from] ParseError),
+    /// An enum variant representing a response body which isn't a JSON object,
+    /// e.g. an HTML error page returned during the central server maintenance.
+    #[error("maintenance or invalid serverinfo response body: {snippet:?}")]
+    MaintenanceOrInvalidBody {
+        /// The `Content-Type` header of the response, if known.
+        content_type: Option<String>,
+        /// The beginning of the body, lossily decoded as UTF-8.
+        snippet: String,
+    },
+    /// An enum variant representing a response with the 401 status code.
+    #[error("unauthorized")]
+    Unauthorized,
+    /// An enum variant representing a response with the 403 status code.
+    #[error("forbidden")]
+    Forbidden,
+    /// An enum variant representing a response with the 429 status code.
+    /// A [`crate::Client`] with a retry policy retries such requests
+    /// waiting at least `retry_after` if the policy allows retrying a 429 response.
+    #[error("rate limited")]
+    RateLimited {
+        /// The delay from the `Retry-After` header, if the response had a valid one.
+        retry_after: Option<Duration>,
+    },
+    /// An enum variant representing a response with a 5xx status code.
+    /// The inner error has the [`ErrorKind::Status`](transport::ErrorKind::Status) kind,
+    /// so such responses are retried and recorded by the circuit breaker like transport errors.
+    #[error(transparent)]
+    ServerError(transport::Error),
+    /// An enum variant representing a request attempted by a [`crate::Client`] with
+    /// [`CooldownMode::Error`](crate::CooldownMode::Error) before the cooldown of the previous response has passed.
+    #[error("cooldown is active for {remaining:?}")]
+    CooldownActive {
+        /// Time left until the cooldown passes.
+        remaining: Duration,
+    },
+}
+
+impl Error {
+    /// Returns `true` if the request may succeed when retried later: transient transport errors,
+    /// 5xx and 429 responses, maintenance pages and the active cooldown. Invalid credentials and malformed responses are fatal.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::{server_info::Error, transport};
+    ///
+    /// assert!(Error::ServerError(transport::Error::status(503)).is_retryable());
+    /// assert!(!Error::Unauthorized.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::TransportError(error) | Error::ServerError(error) => error.is_retryable(),
+            Error::RateLimited { .. }
+            | Error::CooldownActive { .. }
+            | Error::MaintenanceOrInvalidBody { .. } => true,
+            Error::JsonError(_) | Error::ParseError(_) | Error::Unauthorized | Error::Forbidden => {
+                false
+            }
+        }
+    }
+}
+
+/// An enum representing an error of converting a raw `serverinfo` response
+/// which is valid JSON but contains missing or malformed fields.
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::{parse, Error, ParseError};
+///
+/// let error = parse(br#"{"Success":true,"Cooldown":15,"Servers":[{"ID":1,"Port":7777,"Players":"5"}]}"#)
+///     .err()
+///     .unwrap();
+///
+/// assert!(matches!(
+///     error,
+///     Error::ParseError(ParseError::InvalidField { field: "Players", .. })
+/// ));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+pub enum ParseError {
+    /// The response as a whole is inconsistent, see [`ValidationError`].
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+    /// A field has a value which couldn't be parsed.
+    #[error("invalid value of field `{field}`: {value:?}")]
+    InvalidField {
+        /// The field name as returned by the API (e.g. `"Players"`).
+        field: &'static str,
+        /// The offending value.
+        value: String,
+    },
+    /// A field unknown to this crate was found in [`ParseMode::Strict`].
+    #[error("unknown field `{path}`")]
+    UnknownField {
+        /// The field path as reported by [`serde_ignored`](https://docs.rs/serde_ignored) (e.g. `Servers.?.0.Version`).
+        path: String,
+    },
+}
+
+/// The maximum cooldown (in seconds) considered sane, one day.
+pub const MAX_COOLDOWN: u64 = 24 * 60 * 60;
+
+/// An enum representing an inconsistency of a `serverinfo` response detected before its fields are parsed
+/// (see `RawResponse::validate` with the `raw` feature).
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::{parse, Error, ParseError, ValidationError};
+///
+/// let error = parse(br#"{"Success":true,"Servers":[]}"#).err().unwrap();
+///
+/// assert!(matches!(
+///     error,
+///     Error::ParseError(ParseError::ValidationError(ValidationError::MissingCooldown))
+/// ));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, thiserror::Error)]
+pub enum ValidationError {
+    /// A successful response has no `Cooldown` field.
+    #[error("successful response has no cooldown")]
+    MissingCooldown,
+    /// A successful response has no `Servers` field.
+    #[error("successful response has no servers")]
+    MissingServers,
+    /// The cooldown is greater than [`MAX_COOLDOWN`].
+    #[error("cooldown of {0} seconds is out of range")]
+    CooldownOutOfRange(u64),
+    /// An unsuccessful response has no `Error` field.
+    #[error("unsuccessful response has no error message")]
+    MissingError,
+    /// A successful response has an `Error` field.
+    #[error("successful response has an error message: {0:?}")]
+    UnexpectedError(String),
+}
+
+/// An enum representing how strictly the fields of a `serverinfo` response are parsed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ParseMode {
+    /// Fail with [`ParseError`] on any malformed field.
+    #[default]
+    Normal,
+    /// Replace malformed per-server fields (`LastOnline`, `Players` and `Info`) with [`None`]
+    /// and report them in [`SuccessResponse::warnings`], so partial data is returned instead of an error.
+    Lenient,
+    /// Additionally fail with [`ParseError::UnknownField`] if the response contains a field
+    /// unknown to this crate, so changes of the API schema are noticed immediately.
+    /// Fields of players in the `PlayersList` aren't checked.
+    Strict,
+}
+
+/// A struct representing a malformed per-server field skipped in [`ParseMode::Lenient`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseWarning {
+    server_id: u64,
+    error: ParseError,
+}
+
+impl ParseWarning {
+    /// Get a reference to the parse warning's server id.
+    pub fn server_id(&self) -> u64 {
+        self.server_id
+    }
+
+    /// Get a reference to the parse warning's error.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "server {}: {}", self.server_id, self.error)
+    }
+}
+
 /// An enum representing a parsed API response for the `serverinfo` request.
 pub enum Response {
     /// Successful response.
@@ -52,21 +262,54 @@ pub enum Response {
     Error(ErrorResponse),
 }
 
-impl From<RawResponse> for Response {
-    fn from(raw: RawResponse) -> Self {
+impl Response {
+    /// Returns the successful response or the error of the unsuccessful one as an [`ApiError`].
+    /// # Errors
+    /// Returns [`ApiError`] if the response is [`Response::Error`].
+    pub fn into_result(self) -> Result<SuccessResponse, ApiError> {
+        match self {
+            Response::Success(success) => Ok(success),
+            Response::Error(error) => Err(error.api_error()),
+        }
+    }
+}
+
+impl Response {
+    /// Converts the raw response parsing its fields according to the `mode`.
+    /// # Errors
+    /// Returns [`ParseError`] if the response contains missing or malformed fields.
+    /// In [`ParseMode::Lenient`] only [`ValidationError`]s are errors.
+    /// Unknown fields are detected while deserializing the body, so here [`ParseMode::Strict`]
+    /// is equivalent to [`ParseMode::Normal`].
+    pub fn from_raw(raw: RawResponse, mode: ParseMode) -> Result<Self, ParseError> {
+        raw.validate()?;
+
         if let Some(error) = raw.error {
-            Self::Error(ErrorResponse { error })
-        } else {
-            Self::Success(SuccessResponse {
-                cooldown: raw.cooldown.unwrap(),
-                servers: raw
-                    .servers
-                    .unwrap()
-                    .into_iter()
-                    .map(ServerInfo::from)
-                    .collect(),
-            })
+            return Ok(Self::Error(ErrorResponse { error }));
         }
+
+        let mut warnings = Vec::new();
+        let cooldown = raw.cooldown.ok_or(ValidationError::MissingCooldown)?;
+        let servers = raw
+            .servers
+            .ok_or(ValidationError::MissingServers)?
+            .into_iter()
+            .map(|server| ServerInfo::from_raw(server, mode, &mut warnings))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::Success(SuccessResponse {
+            cooldown,
+            servers,
+            warnings,
+        }))
+    }
+}
+
+impl TryFrom<RawResponse> for Response {
+    type Error = ParseError;
+
+    fn try_from(raw: RawResponse) -> Result<Self, ParseError> {
+        Self::from_raw(raw, ParseMode::Normal)
     }
 }
 
@@ -75,6 +318,7 @@ impl From<RawResponse> for Response {
 pub struct SuccessResponse {
     cooldown: u64,
     servers: Vec<ServerInfo>,
+    warnings: Vec<ParseWarning>,
 }
 
 impl SuccessResponse {
@@ -88,6 +332,12 @@ impl SuccessResponse {
         self.servers.as_slice()
     }
 
+    /// Get a reference to the success response's warnings.
+    /// Always empty unless the response was parsed in [`ParseMode::Lenient`].
+    pub fn warnings(&self) -> &[ParseWarning] {
+        self.warnings.as_slice()
+    }
+
     /// Get a mutable reference to the success response's cooldown.
     pub fn cooldown_mut(&mut self) -> &mut u64 {
         &mut self.cooldown
@@ -97,6 +347,11 @@ impl SuccessResponse {
     pub fn servers_mut(&mut self) -> &mut Vec<ServerInfo> {
         &mut self.servers
     }
+
+    /// Get a mutable reference to the success response's warnings.
+    pub fn warnings_mut(&mut self) -> &mut Vec<ParseWarning> {
+        &mut self.warnings
+    }
 }
 
 /// A struct representing an unsuccessful API response for the `serverinfo` request.
@@ -115,25 +370,39 @@ impl ErrorResponse {
     pub fn error_mut(&mut self) -> &mut String {
         &mut self.error
     }
+
+    /// Returns the error response's error recognized as an [`ApiError`].
+    pub fn api_error(&self) -> ApiError {
+        ApiError::parse(self.error.as_str())
+    }
 }
 
 /// A struct representing a server info for the `serverinfo` request.
+#[allow(deprecated)]
 #[derive(Clone, Default)]
 pub struct ServerInfo {
     id: u64,
     port: u16,
+    #[cfg(feature = "chrono")]
     last_online: Option<Date<Utc>>,
+    #[cfg(not(feature = "chrono"))]
+    last_online: Option<DateString>,
     players_count: Option<PlayersCount>,
     players: Option<Vec<Player>>,
     info: Option<String>,
+    info_raw: Option<Vec<u8>>,
     friendly_fire: Option<bool>,
     whitelist: Option<bool>,
     modded: Option<bool>,
     mods: Option<u64>,
     suppress: Option<bool>,
     auto_suppress: Option<bool>,
+    pastebin: Option<String>,
+    version: Option<String>,
+    online: Option<bool>,
 }
 
+#[allow(deprecated)]
 impl ServerInfo {
     /// Get a reference to the server info's id.
     pub fn id(&self) -> u64 {
@@ -146,10 +415,17 @@ impl ServerInfo {
     }
 
     /// Get a reference to the server info's last online.
+    #[cfg(feature = "chrono")]
     pub fn last_online(&self) -> Option<Date<Utc>> {
         self.last_online
     }
 
+    /// Get a reference to the server info's last online.
+    #[cfg(not(feature = "chrono"))]
+    pub fn last_online(&self) -> Option<&DateString> {
+        self.last_online.as_ref()
+    }
+
     /// Get a reference to the server info's players count.
     pub fn players_count(&self) -> Option<&PlayersCount> {
         self.players_count.as_ref()
@@ -161,10 +437,27 @@ impl ServerInfo {
     }
 
     /// Get a reference to the server info's info.
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD`, see [`ServerInfo::info_raw`] for the original bytes.
     pub fn info(&self) -> Option<&String> {
         self.info.as_ref()
     }
 
+    /// Get a reference to the server info's base64-decoded info bytes, which may not be valid UTF-8.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::server_info::parse;
+    ///
+    /// let body = br#"{"Success":true,"Cooldown":15,"Servers":[{"ID":1,"Port":7777,"Info":"/0E="}]}"#;
+    /// let response = parse(body).unwrap().into_result().unwrap();
+    /// let server = &response.servers()[0];
+    ///
+    /// assert_eq!(server.info_raw(), Some(&[0xff, b'A'][..]));
+    /// assert_eq!(server.info().unwrap(), "\u{fffd}A");
+    /// ```
+    pub fn info_raw(&self) -> Option<&[u8]> {
+        self.info_raw.as_deref()
+    }
+
     /// Get a reference to the server info's friendly fire.
     pub fn friendly_fire(&self) -> Option<bool> {
         self.friendly_fire
@@ -195,6 +488,34 @@ impl ServerInfo {
         self.auto_suppress
     }
 
+    /// Get a reference to the server info's pastebin.
+    pub fn pastebin(&self) -> Option<&String> {
+        self.pastebin.as_ref()
+    }
+
+    /// Get a reference to the server info's version.
+    pub fn version(&self) -> Option<&String> {
+        self.version.as_ref()
+    }
+
+    /// Get a reference to the server info's online.
+    pub fn online(&self) -> Option<bool> {
+        self.online
+    }
+
+    /// Returns the server's flags grouped together.
+    /// Returns [`None`] unless all of the flag fields were returned (i.e. `flags` query parameter was used).
+    pub fn flags(&self) -> Option<ServerFlags> {
+        Some(ServerFlags {
+            friendly_fire: self.friendly_fire?,
+            whitelist: self.whitelist?,
+            modded: self.modded?,
+            mods: self.mods?,
+            suppress: self.suppress?,
+            auto_suppress: self.auto_suppress,
+        })
+    }
+
     /// Get a mutable reference to the server info's id.
     pub fn id_mut(&mut self) -> &mut u64 {
         &mut self.id
@@ -206,10 +527,17 @@ impl ServerInfo {
     }
 
     /// Get a mutable reference to the server info's last online.
+    #[cfg(feature = "chrono")]
     pub fn last_online_mut(&mut self) -> &mut Option<Date<Utc>> {
         &mut self.last_online
     }
 
+    /// Get a mutable reference to the server info's last online.
+    #[cfg(not(feature = "chrono"))]
+    pub fn last_online_mut(&mut self) -> &mut Option<DateString> {
+        &mut self.last_online
+    }
+
     /// Get a mutable reference to the server info's players count.
     pub fn players_count_mut(&mut self) -> &mut Option<PlayersCount> {
         &mut self.players_count
@@ -254,41 +582,170 @@ impl ServerInfo {
     pub fn auto_suppress_mut(&mut self) -> &mut Option<bool> {
         &mut self.auto_suppress
     }
+
+    /// Get a mutable reference to the server info's pastebin.
+    pub fn pastebin_mut(&mut self) -> &mut Option<String> {
+        &mut self.pastebin
+    }
+
+    /// Get a mutable reference to the server info's version.
+    pub fn version_mut(&mut self) -> &mut Option<String> {
+        &mut self.version
+    }
+
+    /// Get a mutable reference to the server info's online.
+    pub fn online_mut(&mut self) -> &mut Option<bool> {
+        &mut self.online
+    }
 }
 
-impl From<RawServerInfo> for ServerInfo {
-    fn from(raw: RawServerInfo) -> Self {
-        Self {
+#[allow(deprecated)]
+impl TryFrom<RawServerInfo> for ServerInfo {
+    type Error = ParseError;
+
+    fn try_from(raw: RawServerInfo) -> Result<Self, ParseError> {
+        Self::from_raw(raw, ParseMode::Normal, &mut Vec::new())
+    }
+}
+
+#[allow(deprecated)]
+impl ServerInfo {
+    fn from_raw(
+        raw: RawServerInfo,
+        mode: ParseMode,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Result<Self, ParseError> {
+        let id = raw.id;
+        let info_raw = recover(
+            raw.info
+                .map(|info| base64::decode(&info).map_err(|_| invalid_field("Info", info)))
+                .transpose(),
+            mode,
+            id,
+            warnings,
+        )?;
+
+        Ok(Self {
             id: raw.id,
             port: raw.port,
-            last_online: raw.last_online.map(|last_online| {
-                Date::from_utc(
-                    NaiveDate::parse_from_str(last_online.as_str(), "%Y-%m-%d").unwrap(),
-                    Utc,
-                )
-            }),
-            players_count: raw.players_count.map(|players_count| {
-                let mut splitted = players_count.split('/');
-                PlayersCount {
-                    current_players: splitted.next().unwrap().parse().unwrap(),
-                    max_players: splitted.next().unwrap().parse().unwrap(),
-                }
-            }),
+            #[cfg(feature = "chrono")]
+            last_online: recover(
+                raw.last_online
+                    .map(|last_online| {
+                        date::last_online_date(last_online.as_str())
+                            .and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok())
+                            .map(|date| Date::from_utc(date, Utc))
+                            .ok_or_else(|| invalid_field("LastOnline", last_online))
+                    })
+                    .transpose(),
+                mode,
+                id,
+                warnings,
+            )?,
+            #[cfg(not(feature = "chrono"))]
+            last_online: recover(
+                raw.last_online
+                    .map(|last_online| {
+                        date::last_online_date(last_online.as_str())
+                            .and_then(|date| DateString::parse(&date))
+                            .ok_or_else(|| invalid_field("LastOnline", last_online))
+                    })
+                    .transpose(),
+                mode,
+                id,
+                warnings,
+            )?,
+            players_count: recover(
+                raw.players_count
+                    .map(|players_count| {
+                        PlayersCount::parse(players_count.as_str())
+                            .ok_or_else(|| invalid_field("Players", players_count))
+                    })
+                    .transpose(),
+                mode,
+                id,
+                warnings,
+            )?,
             players: raw
                 .players
                 .map(|players| players.into_iter().map(Player::from).collect()),
-            info: raw.info.map(|info| {
-                std::str::from_utf8(base64::decode(info).unwrap().as_slice())
-                    .unwrap()
-                    .to_string()
-            }),
+            info: info_raw
+                .as_deref()
+                .map(|info| String::from_utf8_lossy(info).into_owned()),
+            info_raw,
             friendly_fire: raw.friendly_fire,
             whitelist: raw.whitelist,
             modded: raw.modded,
             mods: raw.mods,
             suppress: raw.suppress,
             auto_suppress: raw.auto_suppress,
+            pastebin: raw.pastebin,
+            version: raw.version,
+            online: raw.online,
+        })
+    }
+}
+
+/// Replaces the error with [`None`] and a warning in [`ParseMode::Lenient`].
+fn recover<T>(
+    result: Result<Option<T>, ParseError>,
+    mode: ParseMode,
+    server_id: u64,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Option<T>, ParseError> {
+    match (result, mode) {
+        (Err(error), ParseMode::Lenient) => {
+            warnings.push(ParseWarning { server_id, error });
+            Ok(None)
         }
+        (result, _) => result,
+    }
+}
+
+fn invalid_field(field: &'static str, value: String) -> ParseError {
+    ParseError::InvalidField { field, value }
+}
+
+/// A struct representing the server's flags returned when the `flags` query parameter is used.
+#[derive(Clone, Copy, Default)]
+pub struct ServerFlags {
+    friendly_fire: bool,
+    whitelist: bool,
+    modded: bool,
+    mods: u64,
+    suppress: bool,
+    auto_suppress: Option<bool>,
+}
+
+impl ServerFlags {
+    /// Get a reference to the server flags's friendly fire.
+    pub fn friendly_fire(&self) -> bool {
+        self.friendly_fire
+    }
+
+    /// Get a reference to the server flags's whitelist.
+    pub fn whitelist(&self) -> bool {
+        self.whitelist
+    }
+
+    /// Get a reference to the server flags's modded.
+    pub fn modded(&self) -> bool {
+        self.modded
+    }
+
+    /// Get a reference to the server flags's mods.
+    pub fn mods(&self) -> u64 {
+        self.mods
+    }
+
+    /// Get a reference to the server flags's suppress.
+    pub fn suppress(&self) -> bool {
+        self.suppress
+    }
+
+    /// Get a reference to the server flags's auto suppress.
+    pub fn auto_suppress(&self) -> Option<bool> {
+        self.auto_suppress
     }
 }
 
@@ -300,6 +757,37 @@ pub struct PlayersCount {
 }
 
 impl PlayersCount {
+    /// Returns a new instance of the [`PlayersCount`] if `value` is a valid `current/max` string
+    /// as returned in the `Players` field. Whitespace around both numbers is ignored.
+    /// # Examples
+    /// ```
+    /// use scpsl_api::server_info::PlayersCount;
+    ///
+    /// let count = PlayersCount::parse("5/20").unwrap();
+    ///
+    /// assert_eq!((count.current_players(), count.max_players()), (5, 20));
+    /// assert_eq!(PlayersCount::parse(" 5 / 20 ").unwrap().max_players(), 20);
+    /// assert!(PlayersCount::parse("5").is_none());
+    /// assert!(PlayersCount::parse("5/20/0").is_none());
+    /// assert!(PlayersCount::parse("5/").is_none());
+    /// assert!(PlayersCount::parse("-1/20").is_none());
+    /// assert!(PlayersCount::parse("").is_none());
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split('/');
+        let current_players = parts.next()?.trim().parse().ok()?;
+        let max_players = parts.next()?.trim().parse().ok()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            max_players,
+            current_players,
+        })
+    }
+
     /// Get a reference to the players count's max players.
     pub fn max_players(&self) -> u32 {
         self.max_players
@@ -326,6 +814,8 @@ impl PlayersCount {
 pub struct Player {
     id: String,
     nickname: Option<String>,
+    #[cfg(feature = "steam")]
+    steam_profile: Option<SteamProfile>,
 }
 
 impl Player {
@@ -338,13 +828,36 @@ impl Player {
     pub fn nickname(&self) -> Option<&String> {
         self.nickname.as_ref()
     }
+
+    /// Get a reference to the player's Steam profile.
+    /// Returns [`None`] unless it was attached with [`crate::steam::enrich`].
+    #[cfg(feature = "steam")]
+    pub fn steam_profile(&self) -> Option<&SteamProfile> {
+        self.steam_profile.as_ref()
+    }
+
+    /// Get a mutable reference to the player's Steam profile.
+    #[cfg(feature = "steam")]
+    pub fn steam_profile_mut(&mut self) -> &mut Option<SteamProfile> {
+        &mut self.steam_profile
+    }
 }
 
 impl From<RawPlayer> for Player {
     fn from(raw: RawPlayer) -> Self {
         match raw {
-            RawPlayer::UserId(id) => Self { id, nickname: None },
-            RawPlayer::UserIdWithNickname { id, nickname } => Self { id, nickname },
+            RawPlayer::UserId(id) => Self {
+                id,
+                nickname: None,
+                #[cfg(feature = "steam")]
+                steam_profile: None,
+            },
+            RawPlayer::UserIdWithNickname { id, nickname } => Self {
+                id,
+                nickname,
+                #[cfg(feature = "steam")]
+                steam_profile: None,
+            },
         }
     }
 }
@@ -353,7 +866,7 @@ impl From<RawPlayer> for Player {
 pub struct RequestParameters {
     url: Url,
     id: Option<u64>,
-    key: Option<String>,
+    key: Option<ApiKey>,
     last_online: bool,
     players: bool,
     list: bool,
@@ -363,6 +876,10 @@ pub struct RequestParameters {
     flags: bool,
     nicknames: bool,
     online: bool,
+    mirrors: Vec<Url>,
+    parse_mode: ParseMode,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
 }
 
 impl RequestParameters {
@@ -370,6 +887,51 @@ impl RequestParameters {
     pub fn builder() -> RequestParametersBuilder {
         RequestParametersBuilder::new()
     }
+
+    /// Returns the url of the `serverinfo` request with all query parameters applied.
+    /// Together with [`parse`] it allows performing the request with any HTTP client
+    /// (e.g. `fetch` in serverless runtimes).
+    /// The url contains the API key, use [`dry_run`] to get one that is safe to log.
+    pub fn to_url(&self) -> Url {
+        raw::url(self)
+    }
+
+    /// Get a reference to the request parameters's mirrors.
+    pub fn mirrors(&self) -> &[Url] {
+        self.mirrors.as_slice()
+    }
+
+    /// Get a reference to the request parameters's parse mode.
+    pub fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+}
+
+/// Renders the url with the `key` query parameter value replaced with `***` (see [`dry_run`]).
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::RequestParameters;
+/// use url::Url;
+///
+/// let parameters = RequestParameters::builder()
+///     .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+///     .key("secret".to_string())
+///     .build();
+///
+/// assert!(!format!("{:?}", parameters).contains("secret"));
+/// ```
+impl Debug for RequestParameters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("RequestParameters");
+
+        debug
+            .field("url", &raw::redacted_url(self).as_str())
+            .field("mirrors", &self.mirrors)
+            .field("parse_mode", &self.parse_mode);
+        #[cfg(not(target_arch = "wasm32"))]
+        debug.field("timeout", &self.timeout);
+        debug.finish()
+    }
 }
 
 /// A struct representing a builder for the [`RequestParameters`].
@@ -377,7 +939,7 @@ impl RequestParameters {
 pub struct RequestParametersBuilder {
     url: Option<Url>,
     id: Option<u64>,
-    key: Option<String>,
+    key: Option<ApiKey>,
     last_online: bool,
     players: bool,
     list: bool,
@@ -387,6 +949,10 @@ pub struct RequestParametersBuilder {
     flags: bool,
     nicknames: bool,
     online: bool,
+    mirrors: Vec<Url>,
+    parse_mode: ParseMode,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
 }
 
 impl RequestParametersBuilder {
@@ -412,6 +978,10 @@ impl RequestParametersBuilder {
             flags: self.flags,
             nicknames: self.nicknames,
             online: self.online,
+            mirrors: self.mirrors,
+            parse_mode: self.parse_mode,
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: self.timeout,
         }
     }
 
@@ -428,8 +998,8 @@ impl RequestParametersBuilder {
     }
 
     /// Sets the `key` query parameter to be used.
-    pub fn key(mut self, value: String) -> Self {
-        self.key = Some(value);
+    pub fn key<V: Into<ApiKey>>(mut self, value: V) -> Self {
+        self.key = Some(value.into());
         self
     }
 
@@ -486,11 +1056,266 @@ impl RequestParametersBuilder {
         self.online = value;
         self
     }
+
+    /// Adds a mirror url to fail over to, in the order of addition, if the request to the previous url
+    /// fails with a connection error or a 5xx response. Query parameters are applied to mirrors as well.
+    pub fn mirror(mut self, value: Url) -> Self {
+        self.mirrors.push(value);
+        self
+    }
+
+    /// Sets how strictly the response fields are parsed, [`ParseMode::Normal`] by default.
+    pub fn parse_mode(mut self, value: ParseMode) -> Self {
+        self.parse_mode = value;
+        self
+    }
+
+    /// Sets the timeout for the whole request, overriding [`ClientBuilder::timeout`](crate::ClientBuilder::timeout).
+    /// Not available on `wasm32`. It is enforced with the transport's [timer](crate::transport::HttpTransport::sleep),
+    /// blocking transports (e.g. `UreqTransport`) complete the request first, so their own timeouts apply instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+}
+
+/// Parses a `serverinfo` response body received by other means than [`get`].
+/// # Errors
+/// Returns [`Error::MaintenanceOrInvalidBody`] if the body isn't a JSON object.
+/// Returns [`Error::JsonError`] if the body isn't a valid `serverinfo` response.
+/// Returns [`Error::ParseError`] if the response contains missing or malformed fields.
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::{parse, Response};
+///
+/// let response = parse(br#"{"Success":true,"Cooldown":15,"Servers":[]}"#).unwrap();
+///
+/// assert!(matches!(response, Response::Success(_)));
+/// ```
+pub fn parse(body: &[u8]) -> Result<Response, Error> {
+    parse_with_mode(body, ParseMode::Normal)
+}
+
+/// Parses a `serverinfo` response body like [`parse`] according to the `mode`.
+/// # Errors
+/// See [`parse`].
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::{parse_with_mode, ParseMode, Response};
+///
+/// let body = br#"{"Success":true,"Cooldown":15,"Servers":[{"ID":1,"Port":7777,"Players":"?"}]}"#;
+///
+/// if let Response::Success(response) = parse_with_mode(body, ParseMode::Lenient).unwrap() {
+///     assert!(response.servers()[0].players_count().is_none());
+///     assert_eq!(response.warnings()[0].server_id(), 1);
+/// }
+/// ```
+pub fn parse_with_mode(body: &[u8], mode: ParseMode) -> Result<Response, Error> {
+    let raw = raw::parse_with_mode(body, None, mode)?;
+
+    Ok(Response::from_raw(raw, mode)?)
+}
+
+/// Returns the url [`get`] would request with the given `parameters` without performing any I/O,
+/// so the configuration can be checked without spending the rate limit.
+/// The API key is replaced with `***` unless `include_key` is `true`.
+/// # Examples
+/// ```
+/// use scpsl_api::server_info::{dry_run, RequestParameters};
+/// use url::Url;
+///
+/// let parameters = RequestParameters::builder()
+///     .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+///     .id(1)
+///     .key("secret".to_string())
+///     .players(true)
+///     .build();
+///
+/// assert_eq!(
+///     dry_run(&parameters, false).as_str(),
+///     "https://api.scpslgame.com/serverinfo.php?id=1&key=***&players=true"
+/// );
+/// ```
+pub fn dry_run(parameters: &RequestParameters, include_key: bool) -> Url {
+    if include_key {
+        raw::url(parameters)
+    } else {
+        raw::redacted_url(parameters)
+    }
 }
 
 /// Returns info about own servers. See [official API reference](https://api.scpslgame.com/#/default/Get%20Server%20Info).
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client`] to reuse connections.
 /// # Errors
-/// Returns [`Error`] if there was an error in the [`reqwest`] crate.  
-pub async fn get<'a>(parameters: &RequestParameters) -> Result<Response, Error> {
-    raw::get(parameters).await.map(|response| response.into())
+/// Returns [`Error::TransportError`] if the request failed.
+/// Returns [`Error::Unauthorized`], [`Error::Forbidden`], [`Error::RateLimited`] or [`Error::ServerError`]
+/// if the response status was 401, 403, 429 or 5xx respectively.
+/// Returns [`Error::MaintenanceOrInvalidBody`] if the response body isn't a JSON object.
+/// Returns [`Error::JsonError`] if the response body isn't a valid `serverinfo` response.
+/// Returns [`Error::ParseError`] if the response contains missing or malformed fields.
+#[cfg(feature = "reqwest")]
+pub async fn get(parameters: &RequestParameters) -> Result<Response, Error> {
+    let raw = raw::fetch(&ReqwestTransport::new(), parameters).await?;
+
+    Ok(Response::from_raw(raw, parameters.parse_mode())?)
+}
+
+/// Returns info about own servers together with the request durations. See [`get`].
+/// # Errors
+/// See [`get`].
+#[cfg(feature = "reqwest")]
+pub async fn get_timed(parameters: &RequestParameters) -> Result<Timed<Response>, Error> {
+    let start = Instant::now();
+    let (http_response, response) =
+        raw::fetch_with_http_response(&ReqwestTransport::new(), parameters).await?;
+
+    Ok(Timed::new(
+        Response::from_raw(response, parameters.parse_mode())?,
+        start.elapsed(),
+        http_response.timings(),
+    ))
+}
+
+/// Returns info about own servers together with the original response body and headers,
+/// useful for debugging responses that fail to parse or parse unexpectedly. See [`get`].
+/// # Errors
+/// See [`get`].
+#[cfg(feature = "reqwest")]
+pub async fn get_with_raw(parameters: &RequestParameters) -> Result<WithRaw<Response>, Error> {
+    let (http_response, response) =
+        raw::fetch_with_http_response(&ReqwestTransport::new(), parameters).await?;
+
+    Ok(WithRaw::new(
+        Response::from_raw(response, parameters.parse_mode())?,
+        &http_response,
+    ))
+}
+
+/// A struct representing a value returned by a request together with the original response body and headers.
+#[derive(Clone)]
+pub struct WithRaw<T> {
+    value: T,
+    body: String,
+    headers: Vec<(String, String)>,
+}
+
+impl<T> WithRaw<T> {
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn new(value: T, http_response: &HttpResponse) -> Self {
+        Self {
+            value,
+            body: String::from_utf8_lossy(http_response.body()).into_owned(),
+            headers: http_response.headers().to_vec(),
+        }
+    }
+
+    /// Get a reference to the with raw's value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the [`WithRaw`] instance and returns the value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Get a reference to the with raw's response body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Get a reference to the with raw's response headers.
+    pub fn headers(&self) -> &[(String, String)] {
+        self.headers.as_slice()
+    }
+
+    /// Returns the value of the first response header with the given `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A struct representing a value returned by a request together with the request durations.
+#[derive(Clone)]
+pub struct Timed<T> {
+    value: T,
+    total: Duration,
+    timings: Timings,
+}
+
+impl<T> Timed<T> {
+    pub(crate) fn new(value: T, total: Duration, timings: Timings) -> Self {
+        Self {
+            value,
+            total,
+            timings,
+        }
+    }
+
+    /// Get a reference to the timed's value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the [`Timed`] instance and returns the value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Get a reference to the timed's total duration, from sending the request until the response was parsed.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Get a reference to the timed's DNS lookup duration if the transport reported it.
+    /// See [`Timings`] for the transports measuring it.
+    pub fn dns(&self) -> Option<Duration> {
+        self.timings.dns()
+    }
+
+    /// Get a reference to the timed's connection establishment duration if the transport reported it.
+    pub fn connect(&self) -> Option<Duration> {
+        self.timings.connect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlayersCount;
+
+    #[test]
+    fn players_count_parse() {
+        let cases: &[(&str, Option<(u32, u32)>)] = &[
+            ("5/20", Some((5, 20))),
+            ("0/0", Some((0, 0))),
+            (" 5 / 20 ", Some((5, 20))),
+            ("\t5/\n20", Some((5, 20))),
+            ("4294967295/4294967295", Some((u32::MAX, u32::MAX))),
+            ("", None),
+            (" ", None),
+            ("/", None),
+            ("5/", None),
+            ("/20", None),
+            ("5", None),
+            ("a/b", None),
+            ("5/b", None),
+            ("5 0/20", None),
+            ("-1/20", None),
+            ("5/20/0", None),
+            ("5//20", None),
+            ("4294967296/20", None),
+            ("5/99999999999999999999", None),
+        ];
+
+        for (value, expected) in cases {
+            let actual = PlayersCount::parse(value)
+                .map(|count| (count.current_players(), count.max_players()));
+
+            assert_eq!(actual, *expected, "{:?}", value);
+        }
+    }
 }
@@ -0,0 +1,302 @@
+use super::{Player, ServerInfo};
+use std::collections::HashSet;
+
+/// An enum representing a problem found in a server's players list.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PlayersListIssue {
+    /// The player at `index` has an empty id.
+    EmptyId {
+        /// An index of the player in the players list.
+        index: usize,
+    },
+    /// The player at `index` has the same id as a player preceding it.
+    DuplicateId {
+        /// An index of the player in the players list.
+        index: usize,
+        /// A duplicated id.
+        id: String,
+    },
+}
+
+impl PlayersListIssue {
+    /// Returns an index of the player this problem refers to.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::EmptyId { index } | Self::DuplicateId { index, .. } => *index,
+        }
+    }
+}
+
+fn find_issues(players: &[Player]) -> Vec<PlayersListIssue> {
+    let mut seen = HashSet::new();
+    let mut issues = Vec::new();
+
+    for (index, player) in players.iter().enumerate() {
+        if player.id.trim().is_empty() {
+            issues.push(PlayersListIssue::EmptyId { index });
+        } else if !seen.insert(player.id.as_str()) {
+            issues.push(PlayersListIssue::DuplicateId {
+                index,
+                id: player.id.clone(),
+            });
+        }
+    }
+
+    issues
+}
+
+impl ServerInfo {
+    /// Returns the server's players without entries with empty ids and without duplicates,
+    /// keeping the first occurrence of each id in the original order.
+    pub fn players_deduped(&self) -> Vec<&Player> {
+        let mut seen = HashSet::new();
+
+        self.players
+            .iter()
+            .flatten()
+            .filter(|player| !player.id.trim().is_empty() && seen.insert(player.id.as_str()))
+            .collect()
+    }
+
+    /// Returns problems found in the server's players list without modifying it.
+    pub fn validate_players(&self) -> Vec<PlayersListIssue> {
+        self.players.as_deref().map(find_issues).unwrap_or_default()
+    }
+
+    /// Removes entries with empty ids and duplicates from the server's players list
+    /// and returns the problems these entries had.
+    /// Indices in the returned problems refer to the list before cleaning.
+    pub fn clean_players(&mut self) -> Vec<PlayersListIssue> {
+        let players = match &mut self.players {
+            Some(players) => players,
+            None => return Vec::new(),
+        };
+        let issues = find_issues(players);
+        let removed: HashSet<usize> = issues.iter().map(PlayersListIssue::index).collect();
+        let mut index = 0;
+
+        players.retain(|_| {
+            index += 1;
+            !removed.contains(&(index - 1))
+        });
+
+        issues
+    }
+
+    /// Returns the server's players sorted by id, so the order is the same between requests.
+    pub fn players_sorted(&self) -> Vec<&Player> {
+        let mut players: Vec<&Player> = self.players.iter().flatten().collect();
+
+        players.sort_by(|a, b| a.id.cmp(&b.id));
+        players
+    }
+
+    /// Returns the server's players sorted by id and split into pages of at most `page_size` players.
+    /// # Panics
+    /// Panics if `page_size` is 0.
+    pub fn players_pages(&self, page_size: usize) -> Vec<Vec<&Player>> {
+        self.players_sorted()
+            .chunks(page_size)
+            .map(|page| page.to_vec())
+            .collect()
+    }
+
+    /// Formats the server's players sorted by id with `format` and joins them with newlines
+    /// into pages of at most `max_length` characters each (e.g. 1024 for Discord embed fields).
+    /// Lines longer than `max_length` are truncated.
+    /// # Examples
+    /// ```
+    /// # use scpsl_api::server_info::ServerInfo;
+    /// # let server = ServerInfo::default();
+    /// let pages = server.players_pages_formatted(1024, |player| {
+    ///     player.nickname().cloned().unwrap_or_else(|| player.id().to_string())
+    /// });
+    /// ```
+    pub fn players_pages_formatted<F>(&self, max_length: usize, mut format: F) -> Vec<String>
+    where
+        F: FnMut(&Player) -> String,
+    {
+        let mut pages = Vec::new();
+        let mut page = String::new();
+        let mut page_length = 0;
+
+        for player in self.players_sorted() {
+            let line: String = format(player).chars().take(max_length).collect();
+            let line_length = line.chars().count();
+
+            if page_length != 0 && page_length + 1 + line_length > max_length {
+                pages.push(std::mem::take(&mut page));
+                page_length = 0;
+            }
+            if page_length != 0 {
+                page.push('\n');
+                page_length += 1;
+            }
+            page.push_str(line.as_str());
+            page_length += line_length;
+        }
+        if page_length != 0 {
+            pages.push(page);
+        }
+
+        pages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlayersListIssue::{self, DuplicateId, EmptyId};
+    use crate::server_info::{raw::RawPlayer, Player, ServerInfo};
+
+    fn server(ids: &[&str]) -> ServerInfo {
+        ServerInfo {
+            players: Some(
+                ids.iter()
+                    .map(|id| Player {
+                        id: id.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn ids(server: &ServerInfo) -> Vec<&str> {
+        server.players().unwrap().iter().map(Player::id).collect()
+    }
+
+    fn duplicate(index: usize, id: &str) -> PlayersListIssue {
+        DuplicateId {
+            index,
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn validate_and_clean_players() {
+        let cases: &[(&[&str], &[&str], Vec<PlayersListIssue>)] = &[
+            (&[], &[], vec![]),
+            (&["a", "b"], &["a", "b"], vec![]),
+            (
+                &["", "a", "b", "a", "c", "  ", "b"],
+                &["a", "b", "c"],
+                vec![
+                    EmptyId { index: 0 },
+                    duplicate(3, "a"),
+                    EmptyId { index: 5 },
+                    duplicate(6, "b"),
+                ],
+            ),
+            (
+                &["a", "a", "a", "b"],
+                &["a", "b"],
+                vec![duplicate(1, "a"), duplicate(2, "a")],
+            ),
+            (&["a", "b", ""], &["a", "b"], vec![EmptyId { index: 2 }]),
+            (
+                &["", " "],
+                &[],
+                vec![EmptyId { index: 0 }, EmptyId { index: 1 }],
+            ),
+        ];
+
+        for (players, cleaned, issues) in cases {
+            let mut server = server(players);
+
+            assert_eq!(server.validate_players(), *issues, "{:?}", players);
+            assert_eq!(ids(&server), *players, "validating modified {:?}", players);
+            assert_eq!(server.clean_players(), *issues, "{:?}", players);
+            assert_eq!(ids(&server), *cleaned, "{:?}", players);
+            assert_eq!(server.validate_players(), vec![], "{:?}", players);
+        }
+    }
+
+    #[test]
+    fn no_players_list() {
+        let mut server = ServerInfo::default();
+
+        assert!(server.validate_players().is_empty());
+        assert!(server.clean_players().is_empty());
+        assert!(server.players().is_none());
+    }
+
+    fn page_ids<'a>(pages: &[Vec<&'a Player>]) -> Vec<Vec<&'a str>> {
+        pages
+            .iter()
+            .map(|page| page.iter().map(|player| player.id()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn players_pages() {
+        let empty = server(&[]);
+
+        assert!(empty.players_sorted().is_empty());
+        assert!(empty.players_pages(2).is_empty());
+        assert!(ServerInfo::default().players_pages(2).is_empty());
+
+        let server = server(&["d", "b", "a", "c"]);
+
+        assert_eq!(
+            page_ids(&server.players_pages(2)),
+            [vec!["a", "b"], vec!["c", "d"]]
+        );
+        assert_eq!(
+            page_ids(&server.players_pages(3)),
+            [vec!["a", "b", "c"], vec!["d"]]
+        );
+        assert_eq!(
+            page_ids(&server.players_pages(10)),
+            [vec!["a", "b", "c", "d"]]
+        );
+    }
+
+    #[test]
+    fn players_sorted_is_stable() {
+        let player = |id: &str, nickname: &str| {
+            Player::from(RawPlayer::UserIdWithNickname {
+                id: id.to_string(),
+                nickname: Some(nickname.to_string()),
+            })
+        };
+        let server = ServerInfo {
+            players: Some(vec![
+                player("b", "first"),
+                player("a", "second"),
+                player("b", "third"),
+                player("a", "fourth"),
+            ]),
+            ..Default::default()
+        };
+        let nicknames: Vec<&str> = server
+            .players_sorted()
+            .into_iter()
+            .map(|player| player.nickname().unwrap().as_str())
+            .collect();
+
+        assert_eq!(nicknames, ["second", "fourth", "first", "third"]);
+    }
+
+    #[test]
+    fn players_pages_formatted() {
+        let format = |player: &Player| player.id().to_string();
+
+        assert!(server(&[]).players_pages_formatted(10, format).is_empty());
+
+        let server = server(&["bb", "aa", "cc"]);
+
+        // Each page fits exactly: two lines and a newline.
+        assert_eq!(server.players_pages_formatted(5, format), ["aa\nbb", "cc"]);
+        assert_eq!(
+            server.players_pages_formatted(4, format),
+            ["aa", "bb", "cc"]
+        );
+        assert_eq!(server.players_pages_formatted(8, format), ["aa\nbb\ncc"]);
+        assert_eq!(server.players_pages_formatted(1, format), ["a", "b", "c"]);
+        assert_eq!(
+            server.players_pages_formatted(3, |player: &Player| player.id().repeat(2)),
+            ["aaa", "bbb", "ccc"]
+        );
+    }
+}
@@ -3,13 +3,34 @@
 //! May be useful if you want to create your local API proxy
 //! or something like that.
 
-use super::RequestParameters;
+#[cfg(feature = "metrics")]
+use super::ApiError;
+use super::{Error, ParseError, ParseMode, RequestParameters, ValidationError, MAX_COOLDOWN};
 #[cfg(feature = "raw")]
 use super::{Player, Response, ServerInfo};
-use reqwest::Error;
-use serde::Deserialize;
+use crate::time::{SystemTime, UNIX_EPOCH};
+#[cfg(all(feature = "raw", feature = "reqwest"))]
+use crate::transport::ReqwestTransport;
+use crate::transport::{self, HttpResponse, HttpTransport};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{time, transport::ErrorKind};
+#[cfg(feature = "opentelemetry")]
+use opentelemetry::KeyValue;
 #[cfg(feature = "raw")]
 use serde::Serialize;
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserialize, Deserializer,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    convert::TryFrom,
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+    time::Duration,
+};
+use url::Url;
 
 /// A struct representing the raw `serverinfo` response.
 #[cfg_attr(feature = "raw", derive(Serialize, Clone))]
@@ -25,10 +46,40 @@ pub struct RawResponse {
     #[serde(rename = "Servers", skip_serializing_if = "Option::is_none", default)]
     pub servers: Option<Vec<RawServerInfo>>,
     #[allow(missing_docs)]
-    #[serde(rename = "Success", skip_serializing_if = "Option::is_none", default)]
+    #[serde(rename = "Cooldown", skip_serializing_if = "Option::is_none", default)]
     pub cooldown: Option<u64>,
 }
 
+impl RawResponse {
+    /// Checks that the response is consistent: a successful one has a cooldown
+    /// not greater than [`MAX_COOLDOWN`] and servers, an unsuccessful one has an error message
+    /// and only unsuccessful ones have it.
+    /// # Errors
+    /// Returns [`ValidationError`] describing the first inconsistency found.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match (self.success, &self.error) {
+            (true, Some(error)) => return Err(ValidationError::UnexpectedError(error.clone())),
+            (false, None) => return Err(ValidationError::MissingError),
+            (false, Some(_)) => return Ok(()),
+            (true, None) => {}
+        }
+
+        match self.cooldown {
+            None => return Err(ValidationError::MissingCooldown),
+            Some(cooldown) if cooldown > MAX_COOLDOWN => {
+                return Err(ValidationError::CooldownOutOfRange(cooldown))
+            }
+            Some(_) => {}
+        }
+
+        if self.servers.is_none() {
+            return Err(ValidationError::MissingServers);
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "raw")]
 impl From<Response> for RawResponse {
     fn from(response: Response) -> Self {
@@ -60,10 +111,10 @@ impl From<Response> for RawResponse {
 #[derive(Deserialize)]
 pub struct RawServerInfo {
     #[allow(missing_docs)]
-    #[serde(rename = "ID")]
+    #[serde(rename = "ID", deserialize_with = "number")]
     pub id: u64,
     #[allow(missing_docs)]
-    #[serde(rename = "Port")]
+    #[serde(rename = "Port", deserialize_with = "number")]
     pub port: u16,
     #[allow(missing_docs)]
     #[serde(
@@ -95,7 +146,12 @@ pub struct RawServerInfo {
     #[serde(rename = "Modded", skip_serializing_if = "Option::is_none", default)]
     pub modded: Option<bool>,
     #[allow(missing_docs)]
-    #[serde(rename = "Mods", skip_serializing_if = "Option::is_none", default)]
+    #[serde(
+        rename = "Mods",
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "optional_number"
+    )]
     pub mods: Option<u64>,
     #[allow(missing_docs)]
     #[serde(rename = "Suppress", skip_serializing_if = "Option::is_none", default)]
@@ -107,6 +163,15 @@ pub struct RawServerInfo {
         default
     )]
     pub auto_suppress: Option<bool>,
+    #[allow(missing_docs)]
+    #[serde(rename = "Pastebin", skip_serializing_if = "Option::is_none", default)]
+    pub pastebin: Option<String>,
+    #[allow(missing_docs)]
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none", default)]
+    pub version: Option<String>,
+    #[allow(missing_docs)]
+    #[serde(rename = "Online", skip_serializing_if = "Option::is_none", default)]
+    pub online: Option<bool>,
 }
 
 #[cfg(feature = "raw")]
@@ -115,9 +180,14 @@ impl From<ServerInfo> for RawServerInfo {
         Self {
             id: server_info.id,
             port: server_info.port,
+            #[cfg(feature = "chrono")]
             last_online: server_info
                 .last_online
                 .map(|date| date.format("%Y-%m-%d").to_string()),
+            #[cfg(not(feature = "chrono"))]
+            last_online: server_info
+                .last_online
+                .map(|date| date.as_str().to_string()),
             players_count: server_info.players_count.map(|players_count| {
                 format!(
                     "{}/{}",
@@ -127,17 +197,76 @@ impl From<ServerInfo> for RawServerInfo {
             players: server_info
                 .players
                 .map(|players| players.into_iter().map(RawPlayer::from).collect()),
-            info: server_info.info.map(base64::encode),
+            // The original bytes are kept unless the info was changed after parsing.
+            info: match (server_info.info, server_info.info_raw) {
+                (Some(info), Some(info_raw)) if String::from_utf8_lossy(&info_raw) == info => {
+                    Some(base64::encode(info_raw))
+                }
+                (info, _) => info.map(base64::encode),
+            },
             friendly_fire: server_info.friendly_fire,
             whitelist: server_info.whitelist,
             modded: server_info.modded,
             mods: server_info.mods,
             suppress: server_info.suppress,
             auto_suppress: server_info.auto_suppress,
+            pastebin: server_info.pastebin,
+            version: server_info.version,
+            online: server_info.online,
         }
     }
 }
 
+/// A number which may also be represented as a numeric string (e.g. `"7777"`),
+/// as done by some self-hosted mirrors and older API versions.
+struct Number<T>(T);
+
+impl<'de, T: TryFrom<u64> + FromStr> Deserialize<'de> for Number<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NumberVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: TryFrom<u64> + FromStr> Visitor<'de> for NumberVisitor<T> {
+            type Value = Number<T>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                write!(formatter, "a number or a numeric string")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                T::try_from(value)
+                    .map(Number)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value
+                    .trim()
+                    .parse()
+                    .map(Number)
+                    .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor(PhantomData))
+    }
+}
+
+fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64> + FromStr,
+{
+    Number::deserialize(deserializer).map(|number| number.0)
+}
+
+fn optional_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64> + FromStr,
+{
+    Option::<Number<T>>::deserialize(deserializer).map(|number| number.map(|number| number.0))
+}
+
 /// A struct representing a raw player.
 #[cfg_attr(feature = "raw", derive(Serialize, Clone))]
 #[derive(Deserialize)]
@@ -170,11 +299,13 @@ impl From<Player> for RawPlayer {
     }
 }
 
-/// Returns raw info about own servers. See [official API reference](https://api.scpslgame.com/#/default/Get%20Server%20Info).
-/// # Errors
-/// Returns [`Error`] if there was an error in the [`reqwest`] crate.  
-pub async fn get<'a>(parameters: &'a RequestParameters) -> Result<RawResponse, Error> {
-    let mut url = parameters.url.to_owned();
+/// Returns the url of the `serverinfo` request with all query parameters applied.
+pub fn url(parameters: &RequestParameters) -> Url {
+    url_with_base(parameters, &parameters.url)
+}
+
+fn url_with_base(parameters: &RequestParameters, base: &Url) -> Url {
+    let mut url = base.to_owned();
 
     {
         let mut query_parameters = url.query_pairs_mut();
@@ -183,7 +314,7 @@ pub async fn get<'a>(parameters: &'a RequestParameters) -> Result<RawResponse, E
             query_parameters.append_pair("id", id.to_string().as_str());
         }
         if let Some(key) = &parameters.key {
-            query_parameters.append_pair("key", key.as_str());
+            query_parameters.append_pair("key", key.expose_secret());
         }
         if parameters.last_online {
             query_parameters.append_pair("lo", "true");
@@ -214,5 +345,471 @@ pub async fn get<'a>(parameters: &'a RequestParameters) -> Result<RawResponse, E
         }
     }
 
-    Ok(reqwest::get(url).await?.json().await?)
+    url
+}
+
+/// Returns the url of the `serverinfo` request with all query parameters applied
+/// and the `key` query parameter value replaced with `***`.
+pub fn redacted_url(parameters: &RequestParameters) -> Url {
+    transport::redact(&url(parameters))
+}
+
+/// Returns the [`CacheStore`](crate::cache::CacheStore) key of the `serverinfo` request:
+/// the redacted url with the SHA-256 hash of the API key as the fragment,
+/// so responses for different API keys never share an entry.
+pub(crate) fn cache_key(parameters: &RequestParameters) -> String {
+    let mut url = redacted_url(parameters);
+
+    if let Some(key) = &parameters.key {
+        let hash: String = Sha256::digest(key.expose_secret().as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+
+        url.set_fragment(Some(&format!("sha256={}", hash)));
+    }
+
+    url.to_string()
+}
+
+/// Parses a raw `serverinfo` response body.
+/// The response isn't validated, see [`RawResponse::validate`].
+/// # Errors
+/// Returns [`serde_path_to_error::Error`] if the body isn't a valid `serverinfo` response.
+/// Its [`path`](serde_path_to_error::Error::path) points to the offending field, e.g. `Servers[0].Port`.
+/// # Examples
+#[cfg_attr(feature = "raw", doc = "```")]
+#[cfg_attr(not(feature = "raw"), doc = "```ignore")]
+/// use scpsl_api::server_info::raw::parse;
+///
+/// let error = parse(br#"{"Success":true,"Servers":[{"ID":1,"Port":true}]}"#)
+///     .err()
+///     .unwrap();
+///
+/// assert_eq!(error.path().to_string(), "Servers[0].Port");
+/// ```
+pub fn parse(body: &[u8]) -> Result<RawResponse, serde_path_to_error::Error<serde_json::Error>> {
+    serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(body))
+}
+
+/// Parses and validates a raw `serverinfo` response body with the given `Content-Type`,
+/// failing on unknown fields in [`ParseMode::Strict`].
+/// Every response the crate acts on goes through here, so none of them is used unvalidated.
+pub(crate) fn parse_with_mode(
+    body: &[u8],
+    content_type: Option<&str>,
+    mode: ParseMode,
+) -> Result<RawResponse, Error> {
+    check_body(content_type, body)?;
+
+    let response = if mode == ParseMode::Strict {
+        parse_strict(body)?
+    } else {
+        parse(body).map_err(Error::JsonError)?
+    };
+
+    response.validate().map_err(ParseError::from)?;
+
+    Ok(response)
+}
+
+/// Parses a raw `serverinfo` response body failing on unknown fields.
+fn parse_strict(body: &[u8]) -> Result<RawResponse, Error> {
+    let mut unknown_fields = Vec::new();
+    let mut callback = |path: serde_ignored::Path| unknown_fields.push(path.to_string());
+    let response = serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+        &mut serde_json::Deserializer::from_slice(body),
+        &mut callback,
+    ))
+    .map_err(Error::JsonError)?;
+
+    match unknown_fields.into_iter().next() {
+        Some(path) => Err(ParseError::UnknownField { path }.into()),
+        None => Ok(response),
+    }
+}
+
+/// The maximum length of the body snippet in [`Error::MaintenanceOrInvalidBody`].
+const SNIPPET_LENGTH: usize = 256;
+
+/// Returns [`Error::MaintenanceOrInvalidBody`] if the body isn't a JSON object (e.g. an HTML page),
+/// so maintenance pages aren't reported as opaque JSON errors. The content type isn't checked
+/// as PHP serves JSON as `text/html` by default.
+fn check_body(content_type: Option<&str>, body: &[u8]) -> Result<(), Error> {
+    let is_object = body
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'{');
+
+    if is_object {
+        return Ok(());
+    }
+
+    let snippet = String::from_utf8_lossy(&body[..body.len().min(SNIPPET_LENGTH)]);
+
+    Err(Error::MaintenanceOrInvalidBody {
+        content_type: content_type.map(str::to_string),
+        snippet: snippet.trim().to_string(),
+    })
+}
+
+/// Returns raw info about own servers. See [official API reference](https://api.scpslgame.com/#/default/Get%20Server%20Info).
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client`] to reuse connections.
+/// # Errors
+/// Returns [`Error::TransportError`] if the request failed.
+/// Returns [`Error::Unauthorized`], [`Error::Forbidden`], [`Error::RateLimited`] or [`Error::ServerError`]
+/// if the response status was 401, 403, 429 or 5xx respectively.
+/// Returns [`Error::MaintenanceOrInvalidBody`] if the response body isn't a JSON object.
+/// Returns [`Error::JsonError`] if the response body isn't a valid `serverinfo` response.
+/// Returns [`Error::ParseError`] if the response is inconsistent (see [`RawResponse::validate`]).
+#[cfg(all(feature = "raw", feature = "reqwest"))]
+pub async fn get(parameters: &RequestParameters) -> Result<RawResponse, Error> {
+    fetch(&ReqwestTransport::new(), parameters).await
+}
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn fetch(
+    transport: &dyn HttpTransport,
+    parameters: &RequestParameters,
+) -> Result<RawResponse, Error> {
+    fetch_with_http_response(transport, parameters)
+        .await
+        .map(|(_, response)| response)
+}
+
+/// Performs the `serverinfo` request and returns both the HTTP response and the parsed response.
+pub(crate) async fn fetch_with_http_response(
+    transport: &dyn HttpTransport,
+    parameters: &RequestParameters,
+) -> Result<(HttpResponse, RawResponse), Error> {
+    let future = async {
+        let http_response = perform(transport, parameters).await?;
+        let response = parse_with_mode(
+            http_response.body(),
+            http_response.header("Content-Type"),
+            parameters.parse_mode(),
+        )?;
+
+        #[cfg(feature = "metrics")]
+        if !response.success
+            && response.error.as_deref().map(ApiError::parse) == Some(ApiError::RateLimitExceeded)
+        {
+            crate::stats::rate_limited("serverinfo");
+        }
+
+        #[cfg(feature = "opentelemetry")]
+        crate::telemetry::record(
+            response
+                .cooldown
+                .map(|cooldown| KeyValue::new("scpsl.cooldown", cooldown as i64))
+                .into_iter()
+                .chain(
+                    response
+                        .servers
+                        .as_ref()
+                        .map(|servers| KeyValue::new("scpsl.server_count", servers.len() as i64)),
+                ),
+        );
+
+        Ok((http_response, response))
+    };
+    #[cfg(feature = "metrics")]
+    let future = crate::stats::request("serverinfo", future);
+    #[cfg(feature = "opentelemetry")]
+    let future = crate::telemetry::request(
+        "serverinfo",
+        vec![KeyValue::new(
+            "url.full",
+            redacted_url(parameters).to_string(),
+        )],
+        future,
+    );
+    #[cfg(feature = "tracing")]
+    let future = crate::instrument::request(
+        tracing::info_span!("serverinfo", url = %redacted_url(parameters)),
+        future,
+    );
+
+    future.await
+}
+
+async fn perform(
+    transport: &dyn HttpTransport,
+    parameters: &RequestParameters,
+) -> Result<HttpResponse, Error> {
+    let mut result = perform_once(transport, parameters, url(parameters)).await;
+
+    for mirror in &parameters.mirrors {
+        let should_fail_over = match &result {
+            Err(Error::TransportError(error)) => error.is_connect(),
+            Err(Error::ServerError(_)) => true,
+            _ => false,
+        };
+
+        if !should_fail_over {
+            break;
+        }
+
+        result = perform_once(transport, parameters, url_with_base(parameters, mirror)).await;
+    }
+
+    result
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+async fn perform_once(
+    transport: &dyn HttpTransport,
+    parameters: &RequestParameters,
+    url: Url,
+) -> Result<HttpResponse, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let response = match parameters.timeout {
+        Some(timeout) => time::timeout(transport.sleep(timeout), transport.get(&url))
+            .await
+            .unwrap_or_else(|| {
+                Err(transport::Error::new(
+                    ErrorKind::Timeout,
+                    "serverinfo request timed out",
+                ))
+            }),
+        None => transport.get(&url).await,
+    };
+    #[cfg(target_arch = "wasm32")]
+    let response = transport.get(&url).await;
+
+    let response = response.map_err(|error| Error::TransportError(error.with_url(&url)))?;
+
+    #[cfg(feature = "opentelemetry")]
+    crate::telemetry::record([KeyValue::new(
+        "http.response.status_code",
+        i64::from(response.status()),
+    )]);
+    #[cfg(feature = "metrics")]
+    if response.status() == 429 {
+        crate::stats::rate_limited("serverinfo");
+    }
+
+    match response.status() {
+        401 => Err(Error::Unauthorized),
+        403 => Err(Error::Forbidden),
+        429 => Err(Error::RateLimited {
+            retry_after: response.header("Retry-After").and_then(parse_retry_after),
+        }),
+        status @ 500..=599 => Err(Error::ServerError(
+            transport::Error::status(status).with_url(&url),
+        )),
+        _ => Ok(response),
+    }
+}
+
+/// Parses the `Retry-After` header value, which is either a number of seconds
+/// or an HTTP date (e.g. `Sun, 06 Nov 1994 08:49:37 GMT`).
+/// Returns [`None`] if the value is malformed or out of range.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+
+    // A date in the past means the request can be retried immediately.
+    Some(date.saturating_sub(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cache_key, parse, parse_retry_after, parse_with_mode, RawServerInfo};
+    use crate::server_info::{Error, ParseError, ParseMode, RequestParameters, ValidationError};
+    use std::time::Duration;
+    use url::Url;
+
+    #[test]
+    fn cache_key_depends_on_api_key() {
+        let parameters = |key: &str| {
+            RequestParameters::builder()
+                .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+                .key(key)
+                .build()
+        };
+        let first = cache_key(&parameters("first-secret"));
+
+        assert_ne!(first, cache_key(&parameters("second-secret")));
+        assert_eq!(first, cache_key(&parameters("first-secret")));
+        assert!(!first.contains("first-secret"));
+    }
+
+    /// A response to a request with every optional query parameter enabled.
+    const EVERY_PARAMETER: &str = r#"{
+        "Success": true,
+        "Cooldown": 15,
+        "Servers": [
+            {
+                "ID": 61234,
+                "Port": 7777,
+                "LastOnline": "2021-07-14",
+                "Players": "1/25",
+                "PlayersList": [{"ID": "76561198000000001@steam", "Nickname": "Player One"}],
+                "Info": "VGVzdCBzZXJ2ZXI=",
+                "Pastebin": "7wV681fT",
+                "Version": "13.5.1",
+                "FF": false,
+                "WL": false,
+                "Modded": true,
+                "Mods": 4,
+                "Suppress": false,
+                "AutoSuppress": false,
+                "Online": true
+            }
+        ]
+    }"#;
+
+    /// Parses a response with a single server with the given `fields`.
+    fn parse_server(
+        fields: &str,
+    ) -> Result<RawServerInfo, serde_path_to_error::Error<serde_json::Error>> {
+        let body = format!(
+            r#"{{"Success":true,"Cooldown":15,"Servers":[{{{}}}]}}"#,
+            fields
+        );
+
+        parse(body.as_bytes()).map(|response| response.servers.unwrap().remove(0))
+    }
+
+    #[test]
+    fn numbers_and_numeric_strings() {
+        for (port, expected) in [
+            ("7777", 7777),
+            (r#""7777""#, 7777),
+            (r#"" 7777 ""#, 7777),
+            ("3", 3),
+        ] {
+            let server = parse_server(&format!(r#""ID":1,"Port":{}"#, port)).unwrap();
+
+            assert_eq!(server.port, expected, "{}", port);
+        }
+
+        let server = parse_server(r#""ID":"42","Port":7777,"Mods":"4""#).unwrap();
+
+        assert_eq!((server.id, server.mods), (42, Some(4)));
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        let cases = [
+            (
+                r#""ID":1,"Port":"70000""#,
+                "Servers[0].Port",
+                r#"string "70000""#,
+            ),
+            (
+                r#""ID":1,"Port":70000"#,
+                "Servers[0].Port",
+                "integer `70000`",
+            ),
+            (
+                r#""ID":"-1","Port":7777"#,
+                "Servers[0].ID",
+                r#"string "-1""#,
+            ),
+            (r#""ID":-1,"Port":7777"#, "Servers[0].ID", "integer `-1`"),
+            (
+                r#""ID":"7e3","Port":7777"#,
+                "Servers[0].ID",
+                r#"string "7e3""#,
+            ),
+        ];
+
+        for (fields, path, unexpected) in cases {
+            let error = parse_server(fields).err().unwrap();
+
+            assert_eq!(error.path().to_string(), path, "{}", fields);
+            assert!(
+                error.to_string().contains(&format!(
+                    "{}, expected a number or a numeric string",
+                    unexpected
+                )),
+                "{}: {}",
+                fields,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_mode_validates() {
+        let body = br#"{"Success":true,"Cooldown":18446744073709551615,"Servers":[]}"#;
+
+        for mode in [ParseMode::Normal, ParseMode::Lenient, ParseMode::Strict] {
+            assert!(matches!(
+                parse_with_mode(body, None, mode),
+                Err(Error::ParseError(ParseError::ValidationError(
+                    ValidationError::CooldownOutOfRange(u64::MAX)
+                )))
+            ));
+        }
+
+        assert!(matches!(
+            parse_with_mode(br#"{"Success":false}"#, None, ParseMode::Normal),
+            Err(Error::ParseError(ParseError::ValidationError(
+                ValidationError::MissingError
+            )))
+        ));
+    }
+
+    #[test]
+    fn strict_mode_accepts_every_parameter() {
+        let response =
+            parse_with_mode(EVERY_PARAMETER.as_bytes(), None, ParseMode::Strict).unwrap();
+        let server = &response.servers.unwrap()[0];
+
+        assert_eq!(server.pastebin.as_deref(), Some("7wV681fT"));
+        assert_eq!(server.version.as_deref(), Some("13.5.1"));
+        assert_eq!(server.online, Some(true));
+    }
+
+    #[test]
+    fn parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+        assert_eq!(
+            parse_retry_after("18446744073709551615"),
+            Some(Duration::from_secs(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_dates() {
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert!(parse_retry_after("Fri, 31 Dec 9999 23:59:59 GMT").unwrap() > Duration::ZERO);
+    }
+
+    #[test]
+    fn parse_retry_after_hostile() {
+        for value in [
+            "Sun, 06 Nov 2030 99999999999999999:00:00 GMT",
+            "Sun, 06 Nov 2030 3000000000000000:00:00 GMT",
+            "Sun, 06 Nov 2030 24:00:00 GMT",
+            "Sun, 06 Nov 2030 00:60:00 GMT",
+            "Sun, 06 Nov 2030 00:00:61 GMT",
+            "Sun, 32 Nov 2030 00:00:00 GMT",
+            "Sun, 06 Foo 2030 00:00:00 GMT",
+            "Sun, 06 Nov 99999999999 00:00:00 GMT",
+            "Sun, 06 Nov 2030 00:00:00 UTC",
+            "-1",
+            "1.5",
+            "",
+            ", ",
+        ] {
+            assert_eq!(parse_retry_after(value), None, "{:?}", value);
+        }
+    }
 }
@@ -0,0 +1,82 @@
+//! `metrics` instrumentation of API requests.
+
+use crate::{
+    ip, server_info,
+    time::Instant,
+    transport::{self, ErrorKind},
+};
+use metrics::{counter, histogram};
+use std::future::Future;
+
+/// A trait providing the value of the `kind` label of errors.
+pub(crate) trait ErrorLabel {
+    fn label(&self) -> &'static str;
+}
+
+impl ErrorLabel for transport::Error {
+    fn label(&self) -> &'static str {
+        match self.kind() {
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Connect => "connect",
+            ErrorKind::Request => "request",
+            ErrorKind::Body => "body",
+            ErrorKind::BodyTooLarge(_) => "body_too_large",
+            ErrorKind::Status(_) => "status",
+            ErrorKind::CircuitOpen => "circuit_open",
+            ErrorKind::Config => "config",
+            ErrorKind::Other => "other",
+        }
+    }
+}
+
+impl ErrorLabel for server_info::Error {
+    fn label(&self) -> &'static str {
+        match self {
+            server_info::Error::TransportError(error) => error.label(),
+            server_info::Error::JsonError(_) => "json",
+            server_info::Error::ParseError(_) => "parse",
+            server_info::Error::MaintenanceOrInvalidBody { .. } => "invalid_body",
+            server_info::Error::Unauthorized => "unauthorized",
+            server_info::Error::Forbidden => "forbidden",
+            server_info::Error::RateLimited { .. } => "rate_limited",
+            server_info::Error::ServerError(error) => error.label(),
+            server_info::Error::CooldownActive { .. } => "cooldown_active",
+        }
+    }
+}
+
+impl ErrorLabel for ip::Error {
+    fn label(&self) -> &'static str {
+        match self {
+            ip::Error::TransportError(error) => error.label(),
+            ip::Error::AddrParseError(_) => "addr_parse",
+        }
+    }
+}
+
+/// Counts the request `future` to the `endpoint`, its failures and records its duration.
+pub(crate) async fn request<T, E, F>(endpoint: &'static str, future: F) -> Result<T, E>
+where
+    E: ErrorLabel,
+    F: Future<Output = Result<T, E>>,
+{
+    counter!("scpsl_api_requests_total", "endpoint" => endpoint).increment(1);
+
+    let start = Instant::now();
+    let result = future.await;
+
+    histogram!("scpsl_api_request_duration_seconds", "endpoint" => endpoint)
+        .record(start.elapsed().as_secs_f64());
+
+    if let Err(error) = &result {
+        counter!("scpsl_api_errors_total", "endpoint" => endpoint, "kind" => error.label())
+            .increment(1);
+    }
+
+    result
+}
+
+/// Counts a response of the `endpoint` rejecting the request because of the rate limit.
+pub(crate) fn rate_limited(endpoint: &'static str) {
+    counter!("scpsl_api_rate_limited_total", "endpoint" => endpoint).increment(1);
+}
@@ -0,0 +1,297 @@
+//! This module contains functionality that can be used for
+//! enriching players with their Steam profiles using the
+//! [Steam Web API](https://developer.valvesoftware.com/wiki/Steam_Web_API#GetPlayerSummaries_.28v0002.29).
+//! # Examples
+//! ```no_run
+//! use scpsl_api::{server_info::ServerInfo, steam};
+//! use url::Url;
+//!
+//! # async fn example(servers: &mut [ServerInfo]) -> Result<(), steam::Error> {
+//! steam::enrich(
+//!     &Url::parse("https://api.steampowered.com/ISteamUser/GetPlayerSummaries/v2/").unwrap(),
+//!     "STEAM_API_KEY",
+//!     servers
+//!         .iter_mut()
+//!         .flat_map(|server| server.players_mut().iter_mut().flatten()),
+//! )
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    server_info::Player,
+    transport::{self, HttpTransport, ReqwestTransport},
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use url::Url;
+
+/// A maximum count of Steam ids the Steam Web API accepts in a single request.
+const MAX_STEAM_IDS: usize = 100;
+
+/// An enum representing an error of a Steam Web API request.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An enum variant representing [`transport::Error`].
+    /// Responses with a non-2xx status (e.g. 403 for an invalid Steam API key) have
+    /// the [`ErrorKind::Status`](transport::ErrorKind::Status) kind.
+    #[error(transparent)]
+    TransportError(#[from] transport::Error),
+    /// An enum variant representing [`serde_json::Error`].
+    #[error("invalid Steam response: {0}")]
+    JsonError(#[source] serde_json::Error),
+}
+
+impl Error {
+    /// Returns `true` if the request may succeed when retried later, i.e. it failed with a transient transport error.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::TransportError(error) => error.is_retryable(),
+            Error::JsonError(_) => false,
+        }
+    }
+}
+
+/// A struct representing a player's Steam profile.
+#[derive(Clone, Default)]
+pub struct SteamProfile {
+    steam_id: String,
+    persona_name: String,
+    profile_url: String,
+    avatar_url: String,
+}
+
+impl SteamProfile {
+    /// Get a reference to the steam profile's steam id.
+    pub fn steam_id(&self) -> &str {
+        self.steam_id.as_str()
+    }
+
+    /// Get a reference to the steam profile's persona name.
+    pub fn persona_name(&self) -> &str {
+        self.persona_name.as_str()
+    }
+
+    /// Get a reference to the steam profile's profile url.
+    pub fn profile_url(&self) -> &str {
+        self.profile_url.as_str()
+    }
+
+    /// Get a reference to the steam profile's full-size avatar url.
+    pub fn avatar_url(&self) -> &str {
+        self.avatar_url.as_str()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawPlayerSummariesResponse {
+    response: RawPlayerSummaries,
+}
+
+#[derive(Deserialize)]
+struct RawPlayerSummaries {
+    players: Vec<RawPlayerSummary>,
+}
+
+#[derive(Deserialize)]
+struct RawPlayerSummary {
+    steamid: String,
+    personaname: String,
+    profileurl: String,
+    avatarfull: String,
+}
+
+/// Returns the Steam id of the `player` if the player's id has the `@steam` suffix.
+pub fn steam_id(player: &Player) -> Option<&str> {
+    player.id().strip_suffix("@steam")
+}
+
+/// Returns Steam profiles for the `steam_ids` using the `GetPlayerSummaries` request at `url`.
+/// Profiles that don't exist are missing from the result.
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client::get_steam_profiles`]
+/// to reuse connections and the client's configuration.
+/// # Errors
+/// Returns [`Error::TransportError`] if a request failed or its response status wasn't 2xx.
+/// Returns [`Error::JsonError`] if a response body isn't a valid `GetPlayerSummaries` response.
+pub async fn get_profiles(
+    url: &Url,
+    key: &str,
+    steam_ids: &[&str],
+) -> Result<Vec<SteamProfile>, Error> {
+    fetch_profiles(&ReqwestTransport::new(), url, key, steam_ids).await
+}
+
+pub(crate) async fn fetch_profiles(
+    transport: &dyn HttpTransport,
+    url: &Url,
+    key: &str,
+    steam_ids: &[&str],
+) -> Result<Vec<SteamProfile>, Error> {
+    let mut profiles = Vec::with_capacity(steam_ids.len());
+
+    for chunk in steam_ids.chunks(MAX_STEAM_IDS) {
+        let mut url = url.to_owned();
+
+        url.query_pairs_mut()
+            .append_pair("key", key)
+            .append_pair("steamids", chunk.join(",").as_str());
+
+        // Errors get the redacted url, as it contains the API key.
+        let response = transport
+            .get(&url)
+            .await
+            .map_err(|error| error.with_url(&url))?;
+
+        if !(200..300).contains(&response.status()) {
+            return Err(transport::Error::status(response.status())
+                .with_url(&url)
+                .into());
+        }
+
+        let response: RawPlayerSummariesResponse =
+            serde_json::from_slice(response.body()).map_err(Error::JsonError)?;
+
+        profiles.extend(
+            response
+                .response
+                .players
+                .into_iter()
+                .map(|summary| SteamProfile {
+                    steam_id: summary.steamid,
+                    persona_name: summary.personaname,
+                    profile_url: summary.profileurl,
+                    avatar_url: summary.avatarfull,
+                }),
+        );
+    }
+
+    Ok(profiles)
+}
+
+/// Fetches Steam profiles of the `@steam` players among `players` (see [`get_profiles`])
+/// and attaches them to the players. These can then be obtained with [`Player::steam_profile`].
+/// Creates a new [`reqwest::Client`] for every call, use [`crate::Client::enrich_steam_profiles`]
+/// to reuse connections and the client's configuration.
+/// # Errors
+/// See [`get_profiles`].
+pub async fn enrich<'a, I>(url: &Url, key: &str, players: I) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a mut Player>,
+{
+    attach_profiles(&ReqwestTransport::new(), url, key, players).await
+}
+
+pub(crate) async fn attach_profiles<'a, I>(
+    transport: &dyn HttpTransport,
+    url: &Url,
+    key: &str,
+    players: I,
+) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a mut Player>,
+{
+    let mut players: Vec<&mut Player> = players
+        .into_iter()
+        .filter(|player| steam_id(player).is_some())
+        .collect();
+    let mut steam_ids: Vec<&str> = players
+        .iter()
+        .map(|player| steam_id(player).unwrap())
+        .collect();
+
+    steam_ids.sort_unstable();
+    steam_ids.dedup();
+
+    let profiles: HashMap<String, SteamProfile> = fetch_profiles(transport, url, key, &steam_ids)
+        .await?
+        .into_iter()
+        .map(|profile| (profile.steam_id.clone(), profile))
+        .collect();
+
+    for player in players.iter_mut() {
+        let profile = profiles.get(steam_id(player).unwrap()).cloned();
+
+        *player.steam_profile_mut() = profile;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attach_profiles, fetch_profiles, Error};
+    use crate::{
+        server_info::{raw::RawPlayer, Player},
+        transport::{self, HttpResponse, HttpTransport},
+    };
+    use async_trait::async_trait;
+    use url::Url;
+
+    /// A transport answering every request with the same status and body.
+    struct StubTransport(u16, &'static str);
+
+    #[async_trait]
+    impl HttpTransport for StubTransport {
+        async fn get(&self, _url: &Url) -> Result<HttpResponse, transport::Error> {
+            Ok(HttpResponse::new(self.0, Vec::new(), self.1.into()))
+        }
+    }
+
+    fn url() -> Url {
+        Url::parse("https://api.steampowered.com/ISteamUser/GetPlayerSummaries/v2/").unwrap()
+    }
+
+    #[test]
+    fn status_is_checked() {
+        let error = pollster::block_on(fetch_profiles(
+            &StubTransport(403, "<html>Forbidden</html>"),
+            &url(),
+            "secret",
+            &["76561198000000001"],
+        ))
+        .err()
+        .unwrap();
+
+        match error {
+            Error::TransportError(error) => {
+                assert_eq!(error.status_code(), Some(403));
+                assert!(!error.to_string().contains("secret"));
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn profiles_are_attached() {
+        let body = r#"{"response":{"players":[{
+            "steamid": "76561198000000001",
+            "personaname": "One",
+            "profileurl": "https://steamcommunity.com/id/one/",
+            "avatarfull": "https://avatars.steamstatic.com/one_full.jpg"
+        }]}}"#;
+        let mut players: Vec<Player> = [
+            "76561198000000001@steam",
+            "76561198000000002@steam",
+            "1234@discord",
+        ]
+        .iter()
+        .map(|id| Player::from(RawPlayer::UserId(id.to_string())))
+        .collect();
+
+        pollster::block_on(attach_profiles(
+            &StubTransport(200, body),
+            &url(),
+            "secret",
+            &mut players,
+        ))
+        .unwrap();
+
+        let persona_names: Vec<Option<&str>> = players
+            .iter()
+            .map(|player| player.steam_profile().map(|profile| profile.persona_name()))
+            .collect();
+
+        assert_eq!(persona_names, [Some("One"), None, None]);
+    }
+}
@@ -0,0 +1,43 @@
+//! OpenTelemetry instrumentation of API requests.
+
+use opentelemetry::{
+    context::FutureExt,
+    global,
+    trace::{get_active_span, SpanKind, Status, TraceContextExt, Tracer},
+    Context, KeyValue,
+};
+use std::{fmt::Debug, future::Future};
+
+/// Runs the request `future` inside a new client span named `name` with the given `attributes`
+/// and marks the span as failed if the request fails.
+pub(crate) async fn request<T, E, F>(
+    name: &'static str,
+    attributes: Vec<KeyValue>,
+    future: F,
+) -> Result<T, E>
+where
+    E: Debug,
+    F: Future<Output = Result<T, E>>,
+{
+    let tracer = global::tracer("scpsl-api");
+    let span = tracer
+        .span_builder(name)
+        .with_kind(SpanKind::Client)
+        .with_attributes(attributes)
+        .start(&tracer);
+    let cx = Context::current_with_span(span);
+    let result = future.with_context(cx.clone()).await;
+    let span = cx.span();
+
+    if let Err(error) = &result {
+        span.set_status(Status::error(format!("{:?}", error)));
+    }
+    span.end();
+
+    result
+}
+
+/// Adds the `attributes` to the span of the current request.
+pub(crate) fn record<I: IntoIterator<Item = KeyValue>>(attributes: I) {
+    get_active_span(|span| span.set_attributes(attributes));
+}
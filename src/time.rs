@@ -0,0 +1,127 @@
+//! Runtime-agnostic timers: a helper thread on native targets and browser timers on `wasm32`.
+//! Transports tied to a runtime override [`HttpTransport::sleep`](crate::transport::HttpTransport::sleep)
+//! to use its timers instead.
+
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Waits for `duration` on a helper thread, so it neither needs an async runtime
+/// nor blocks the executor.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    if !duration.is_zero() {
+        Sleep::new(duration).await
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+/// Returns [`None`] if `sleep` completes before the `future`.
+/// The `future` is polled first, so blocking futures completing on the first poll are never cut off.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn timeout<S, F>(sleep: S, future: F) -> Option<F::Output>
+where
+    S: Future<Output = ()>,
+    F: Future,
+{
+    let mut sleep = pin!(sleep);
+    let mut future = pin!(future);
+
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+
+        sleep.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
+
+/// A future completed by a thread sleeping for the duration.
+#[cfg(not(target_arch = "wasm32"))]
+struct Sleep {
+    duration: Duration,
+    /// Whether the thread has finished sleeping and the waker to notify when it does,
+    /// [`None`] until the first poll starts the thread.
+    state: Option<Arc<Mutex<(bool, Waker)>>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Sleep {
+    fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            state: None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        match &self.state {
+            Some(state) => {
+                let mut state = state.lock().unwrap();
+
+                if state.0 {
+                    return Poll::Ready(());
+                }
+
+                state.1.clone_from(cx.waker());
+            }
+            None => {
+                let state = Arc::new(Mutex::new((false, cx.waker().clone())));
+                let duration = self.duration;
+
+                self.state = Some(state.clone());
+                thread::spawn(move || {
+                    thread::sleep(duration);
+
+                    let mut state = state.lock().unwrap();
+
+                    state.0 = true;
+                    state.1.wake_by_ref();
+                });
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sleep, timeout, Instant};
+    use std::{future::pending, time::Duration};
+
+    #[test]
+    fn timeout_without_runtime() {
+        let start = Instant::now();
+
+        assert_eq!(
+            pollster::block_on(timeout(sleep(Duration::from_millis(20)), pending::<()>())),
+            None
+        );
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(
+            pollster::block_on(timeout(sleep(Duration::from_secs(60)), async { 1 })),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use super::{Error, ErrorKind, HttpResponse, HttpTransport, Timings};
+use crate::time::Instant;
+use async_trait::async_trait;
+use bytes::Bytes;
+use http_body_util::{BodyExt, Empty, LengthLimitError, Limited};
+use hyper::{
+    rt::{Read, ReadBufCursor, Write},
+    Uri,
+};
+use hyper_util::{
+    client::legacy::{
+        connect::{
+            dns::{GaiResolver, Name},
+            Connect, Connected, Connection, HttpConnector,
+        },
+        Client,
+    },
+    rt::TokioExecutor,
+};
+use std::{
+    cell::Cell,
+    future::Future,
+    io::{self, IoSlice},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower_service::Service;
+use url::Url;
+
+tokio::task_local! {
+    /// The DNS lookup duration of the connection being established by [`TimingConnector`].
+    static DNS: Cell<Option<Duration>>;
+}
+
+/// A struct representing a [`HttpTransport`] implemented directly with a [`hyper`] client,
+/// generic over the connector so an existing one (e.g. with TLS from `hyper-rustls` or `hyper-tls`)
+/// and its connection pool can be shared with the rest of the service.
+/// Cloning is cheap and clones share the same connection pool.
+/// Connection [`Timings`] are reported if the connector is wrapped in a [`TimingConnector`],
+/// as done by [`HyperTransport::new`].
+/// # Examples
+/// ```no_run
+/// use scpsl_api::{
+///     transport::{HyperTransport, TimingConnector, TimingResolver},
+///     Client,
+/// };
+/// use hyper_util::client::legacy::connect::HttpConnector;
+///
+/// let mut connector = HttpConnector::new_with_resolver(TimingResolver::new());
+/// connector.set_keepalive(Some(std::time::Duration::from_secs(60)));
+///
+/// let transport = HyperTransport::with_connector(TimingConnector::new(connector));
+/// let client = Client::with_transport(transport);
+/// ```
+#[derive(Clone)]
+pub struct HyperTransport<C> {
+    client: Client<C, Empty<Bytes>>,
+    max_body_size: Option<u64>,
+}
+
+/// Supports only plain `http` urls, use [`HyperTransport::with_connector`] with a TLS connector for `https`.
+impl Default for HyperTransport<TimingConnector<HttpConnector<TimingResolver>>> {
+    fn default() -> Self {
+        Self::with_connector(TimingConnector::new(HttpConnector::new_with_resolver(
+            TimingResolver::new(),
+        )))
+    }
+}
+
+impl HyperTransport<TimingConnector<HttpConnector<TimingResolver>>> {
+    /// Returns a new instance of the [`HyperTransport`] using the plain [`HttpConnector`],
+    /// which supports only `http` urls, reporting the DNS lookup and connection durations.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<C> HyperTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    /// Returns a new instance of the [`HyperTransport`] using the given `connector`
+    /// and the default client settings.
+    pub fn with_connector(connector: C) -> Self {
+        Self::from(Client::builder(TokioExecutor::new()).build(connector))
+    }
+}
+
+impl<C> HyperTransport<C> {
+    /// Returns the transport aborting downloads of response bodies larger than `limit` bytes
+    /// with [`ErrorKind::BodyTooLarge`].
+    pub fn with_max_body_size(mut self, limit: u64) -> Self {
+        self.max_body_size = Some(limit);
+        self
+    }
+
+    /// Get a reference to the hyper transport's client.
+    pub fn client(&self) -> &Client<C, Empty<Bytes>> {
+        &self.client
+    }
+
+    /// Get a reference to the hyper transport's max body size.
+    pub fn max_body_size(&self) -> Option<u64> {
+        self.max_body_size
+    }
+}
+
+impl<C> From<Client<C, Empty<Bytes>>> for HyperTransport<C> {
+    fn from(client: Client<C, Empty<Bytes>>) -> Self {
+        Self {
+            client,
+            max_body_size: None,
+        }
+    }
+}
+
+impl From<hyper_util::client::legacy::Error> for Error {
+    fn from(error: hyper_util::client::legacy::Error) -> Self {
+        let kind = if error.is_connect() {
+            ErrorKind::Connect
+        } else {
+            ErrorKind::Request
+        };
+
+        Error::new(kind, error)
+    }
+}
+
+#[async_trait]
+impl<C> HttpTransport for HyperTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    async fn get(&self, url: &Url) -> Result<HttpResponse, Error> {
+        let uri = url
+            .as_str()
+            .parse::<Uri>()
+            .map_err(|error| Error::new(ErrorKind::Other, error))?;
+        let response = self.client.get(uri).await?;
+        // Only the first response on a connection took the time to establish it.
+        let timings = response
+            .extensions()
+            .get::<ConnectionTimings>()
+            .and_then(|timings| timings.0.lock().unwrap().take())
+            .unwrap_or_default();
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        let body = match self.max_body_size {
+            Some(limit) => Limited::new(response.into_body(), limit as usize)
+                .collect()
+                .await
+                .map_err(|error| {
+                    if error.is::<LengthLimitError>() {
+                        Error::body_too_large(limit)
+                    } else {
+                        Error::new(ErrorKind::Body, error)
+                    }
+                })?
+                .to_bytes(),
+            None => response
+                .into_body()
+                .collect()
+                .await
+                .map_err(|error| Error::new(ErrorKind::Body, error))?
+                .to_bytes(),
+        };
+
+        let mut response = HttpResponse::new(status, headers, body);
+
+        *response.timings_mut() = timings;
+
+        Ok(response)
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+/// A struct representing a connector measuring how long establishing connections takes,
+/// reported in the [`Timings`] of the first response on each new connection.
+/// The DNS lookup duration is only known if the `inner` connector resolves names with a [`TimingResolver`],
+/// otherwise it is included in the connection duration.
+#[derive(Clone)]
+pub struct TimingConnector<C> {
+    inner: C,
+}
+
+impl<C> TimingConnector<C> {
+    /// Returns a new instance of the [`TimingConnector`] wrapping the `inner` connector.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Get a reference to the timing connector's inner connector.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C> Service<Uri> for TimingConnector<C>
+where
+    C: Service<Uri>,
+    C::Future: Send + 'static,
+    C::Response: Connection,
+{
+    type Response = TimedConnection<C::Response>;
+    type Error = C::Error;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        let connecting = self.inner.call(dst);
+
+        Box::pin(DNS.scope(Cell::new(None), async move {
+            let start = Instant::now();
+            let inner = connecting.await?;
+            let dns = DNS.with(Cell::get);
+            let timings = Timings {
+                dns,
+                connect: Some(start.elapsed().saturating_sub(dns.unwrap_or_default())),
+            };
+
+            Ok(TimedConnection {
+                inner,
+                timings: ConnectionTimings(Arc::new(Mutex::new(Some(timings)))),
+            })
+        }))
+    }
+}
+
+/// A struct representing a DNS resolver measuring how long lookups take,
+/// to be used by the connector wrapped in a [`TimingConnector`].
+#[derive(Clone)]
+pub struct TimingResolver<R = GaiResolver> {
+    inner: R,
+}
+
+impl Default for TimingResolver {
+    fn default() -> Self {
+        Self::with_resolver(GaiResolver::new())
+    }
+}
+
+impl TimingResolver {
+    /// Returns a new instance of the [`TimingResolver`] wrapping the system resolver.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<R> TimingResolver<R> {
+    /// Returns a new instance of the [`TimingResolver`] wrapping the `inner` resolver.
+    pub fn with_resolver(inner: R) -> Self {
+        Self { inner }
+    }
+}
+
+impl<R> Service<Name> for TimingResolver<R>
+where
+    R: Service<Name>,
+    R::Future: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = R::Error;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        let resolving = self.inner.call(name);
+
+        Box::pin(async move {
+            let start = Instant::now();
+            let result = resolving.await;
+
+            // Outside of a TimingConnector there is nowhere to report the duration to.
+            let _ = DNS.try_with(|dns| dns.set(Some(start.elapsed())));
+
+            result
+        })
+    }
+}
+
+/// Timings of establishing a connection, taken by the first response on it.
+#[derive(Clone)]
+struct ConnectionTimings(Arc<Mutex<Option<Timings>>>);
+
+/// A struct representing a connection established by a [`TimingConnector`].
+pub struct TimedConnection<T> {
+    inner: T,
+    timings: ConnectionTimings,
+}
+
+impl<T: Connection> Connection for TimedConnection<T> {
+    fn connected(&self) -> Connected {
+        self.inner.connected().extra(self.timings.clone())
+    }
+}
+
+impl<T: Read + Unpin> Read for TimedConnection<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: ReadBufCursor<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<T: Write + Unpin> Write for TimedConnection<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperTransport;
+    use crate::transport::HttpTransport;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+    use url::Url;
+
+    #[tokio::test]
+    async fn timings_of_new_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            for _ in 0..2 {
+                let _ = stream.read(&mut [0; 1024]);
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+                    .unwrap();
+            }
+        });
+
+        let transport = HyperTransport::new();
+        let url = Url::parse(&format!("http://localhost:{}/", port)).unwrap();
+        let first = transport.get(&url).await.unwrap().timings();
+        let second = transport.get(&url).await.unwrap().timings();
+
+        assert!(first.dns().is_some() && first.connect().is_some());
+        // The second request reuses the connection.
+        assert!(second.dns().is_none() && second.connect().is_none());
+    }
+}
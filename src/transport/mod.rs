@@ -0,0 +1,337 @@
+//! This module contains the [`HttpTransport`] trait these can be used
+//! for performing API requests through any HTTP client.
+//! The [`reqwest`](https://docs.rs/reqwest)-based [`ReqwestTransport`] is available
+//! with the `reqwest` feature (enabled by default), the blocking
+//! [`ureq`](https://docs.rs/ureq)-based `UreqTransport` is available with the `ureq` feature
+//! and the [`hyper`](https://docs.rs/hyper)-based `HyperTransport` is available with the `hyper` feature.
+//! # Examples
+//! ```
+//! use async_trait::async_trait;
+//! use scpsl_api::transport::{Error, HttpResponse, HttpTransport};
+//! use url::Url;
+//!
+//! /// A transport answering every request with the same body.
+//! struct Static(&'static str);
+//!
+//! #[async_trait]
+//! impl HttpTransport for Static {
+//!     async fn get(&self, _url: &Url) -> Result<HttpResponse, Error> {
+//!         Ok(HttpResponse::new(200, Vec::new(), self.0.into()))
+//!     }
+//! }
+//! ```
+
+#[cfg(all(feature = "hyper", not(target_arch = "wasm32")))]
+mod hyper;
+#[cfg(feature = "reqwest")]
+mod reqwest;
+#[cfg(all(feature = "ureq", not(target_arch = "wasm32")))]
+mod ureq;
+
+#[cfg(all(feature = "hyper", not(target_arch = "wasm32")))]
+pub use self::hyper::{HyperTransport, TimedConnection, TimingConnector, TimingResolver};
+#[cfg(feature = "reqwest")]
+pub use self::reqwest::ReqwestTransport;
+#[cfg(all(feature = "ureq", not(target_arch = "wasm32")))]
+pub use self::ureq::UreqTransport;
+use crate::time;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    time::Duration,
+};
+use url::Url;
+
+/// A trait representing an HTTP client performing `GET` requests.
+/// On `wasm32` implementations should use `#[async_trait(?Send)]` instead of `#[async_trait]`.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait HttpTransport: Send + Sync {
+    /// Performs a `GET` request to the `url` and returns the response with any status.
+    /// # Errors
+    /// Returns [`Error`] if no complete response was received.
+    async fn get(&self, url: &Url) -> Result<HttpResponse, Error>;
+
+    /// Waits for `duration`. A [`Client`](crate::Client) waits with the timer of its transport for retry backoff,
+    /// [`CooldownMode::Wait`](crate::CooldownMode::Wait), rate limiting, request timeouts and hedging.
+    /// The default implementation works with any executor: it sleeps on a helper thread
+    /// (browser timers are used on `wasm32`). Transports tied to an async runtime should use its timers instead.
+    async fn sleep(&self, duration: Duration) {
+        time::sleep(duration).await
+    }
+}
+
+/// A struct representing an HTTP response returned by a [`HttpTransport`].
+#[derive(Clone, Default)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+    timings: Timings,
+}
+
+impl HttpResponse {
+    /// Returns a new instance of the [`HttpResponse`].
+    pub fn new(status: u16, headers: Vec<(String, String)>, body: Bytes) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+            timings: Default::default(),
+        }
+    }
+
+    /// Get a reference to the http response's status.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Get a reference to the http response's headers.
+    pub fn headers(&self) -> &[(String, String)] {
+        self.headers.as_slice()
+    }
+
+    /// Returns the value of the first header with the given `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get a reference to the http response's body.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Get a reference to the http response's timings.
+    pub fn timings(&self) -> Timings {
+        self.timings
+    }
+
+    /// Get a mutable reference to the http response's timings.
+    pub fn timings_mut(&mut self) -> &mut Timings {
+        &mut self.timings
+    }
+}
+
+/// A struct representing durations of the connection phases of a request, reported by transports able to measure them.
+/// The `hyper`-based `HyperTransport` reports them for new connections made through a `TimingConnector`
+/// (the default), responses on reused connections and of the other built-in transports have no timings.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Timings {
+    dns: Option<Duration>,
+    connect: Option<Duration>,
+}
+
+impl Timings {
+    /// Get a reference to the timings's DNS lookup duration.
+    pub fn dns(&self) -> Option<Duration> {
+        self.dns
+    }
+
+    /// Get a reference to the timings's connection establishment duration (including TLS handshake).
+    pub fn connect(&self) -> Option<Duration> {
+        self.connect
+    }
+
+    /// Get a mutable reference to the timings's DNS lookup duration.
+    pub fn dns_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.dns
+    }
+
+    /// Get a mutable reference to the timings's connection establishment duration.
+    pub fn connect_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.connect
+    }
+}
+
+/// An enum representing a kind of the transport [`Error`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    /// The request timed out.
+    Timeout,
+    /// A connection couldn't be established.
+    Connect,
+    /// The request failed after the connection was established (e.g. the connection was reset).
+    Request,
+    /// The response body couldn't be read.
+    Body,
+    /// The response body exceeded the maximum size (in bytes) configured on the transport.
+    BodyTooLarge(u64),
+    /// The response had an unsuccessful HTTP status.
+    Status(u16),
+    /// The request wasn't performed because the client's
+    /// [`CircuitBreaker`](crate::circuit_breaker::CircuitBreaker) is open.
+    CircuitOpen,
+    /// The client is misconfigured (e.g. no transport is available or the user agent is invalid).
+    Config,
+    /// Any other error (e.g. an invalid url).
+    Other,
+}
+
+/// A struct representing an error of a [`HttpTransport`].
+/// Errors returned by this crate's requests also carry the redacted url of the request
+/// and the number of the attempt that failed, both included in the [`Display`] output.
+pub struct Error {
+    kind: ErrorKind,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    url: Option<Box<Url>>,
+    attempt: Option<u32>,
+}
+
+impl Error {
+    /// Returns a new instance of the [`Error`] caused by `source`.
+    pub fn new<E>(kind: ErrorKind, source: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self {
+            kind,
+            source: Some(source.into()),
+            url: None,
+            attempt: None,
+        }
+    }
+
+    /// Returns a new instance of the [`Error`] for a response with an unsuccessful HTTP `status`.
+    pub fn status(status: u16) -> Self {
+        Self {
+            kind: ErrorKind::Status(status),
+            source: None,
+            url: None,
+            attempt: None,
+        }
+    }
+
+    /// Returns a new instance of the [`Error`] with the [`ErrorKind::BodyTooLarge`] kind.
+    pub fn body_too_large(limit: u64) -> Self {
+        Self {
+            kind: ErrorKind::BodyTooLarge(limit),
+            source: None,
+            url: None,
+            attempt: None,
+        }
+    }
+
+    /// Returns the error with the url of the failed request, the `key` query parameter is redacted.
+    pub(crate) fn with_url(mut self, url: &Url) -> Self {
+        self.url = Some(Box::new(redact(url)));
+        self
+    }
+
+    pub(crate) fn set_attempt(&mut self, attempt: u32) {
+        self.attempt = Some(attempt);
+    }
+
+    /// Get a reference to the error's kind.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Get a reference to the error's request url with the `key` query parameter replaced by `***`.
+    /// Returns [`None`] if the error wasn't returned by a request of this crate.
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_deref()
+    }
+
+    /// Get a reference to the error's attempt number, starting at 1.
+    /// Returns [`None`] if the request wasn't performed by a [`Client`](crate::Client).
+    pub fn attempt(&self) -> Option<u32> {
+        self.attempt
+    }
+
+    /// Returns `true` if the error is [`ErrorKind::Timeout`].
+    pub fn is_timeout(&self) -> bool {
+        self.kind == ErrorKind::Timeout
+    }
+
+    /// Returns `true` if the error is [`ErrorKind::Connect`].
+    pub fn is_connect(&self) -> bool {
+        self.kind == ErrorKind::Connect
+    }
+
+    /// Returns the HTTP status if the error is [`ErrorKind::Status`].
+    pub fn status_code(&self) -> Option<u16> {
+        match self.kind {
+            ErrorKind::Status(status) => Some(status),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the request may succeed when retried. See [`is_transient`](crate::retry::is_transient).
+    pub fn is_retryable(&self) -> bool {
+        crate::retry::is_transient(self)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ErrorKind::Timeout => write!(f, "request timed out")?,
+            ErrorKind::Connect => write!(f, "connection error")?,
+            ErrorKind::Request => write!(f, "request error")?,
+            ErrorKind::Body => write!(f, "response body error")?,
+            ErrorKind::BodyTooLarge(limit) => {
+                write!(f, "response body exceeds the limit of {} bytes", limit)?
+            }
+            ErrorKind::Status(status) => write!(f, "HTTP status {}", status)?,
+            ErrorKind::CircuitOpen => write!(f, "circuit breaker is open")?,
+            ErrorKind::Config => write!(f, "invalid client configuration")?,
+            ErrorKind::Other => write!(f, "transport error")?,
+        }
+        match (&self.url, self.attempt) {
+            (Some(url), Some(attempt)) => write!(f, " (url: {}, attempt {})", url, attempt)?,
+            (Some(url), None) => write!(f, " (url: {})", url)?,
+            (None, Some(attempt)) => write!(f, " (attempt {})", attempt)?,
+            (None, None) => {}
+        }
+        if let Some(source) = &self.source {
+            write!(f, ": {}", source)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Error")
+            .field("kind", &self.kind)
+            .field("source", &self.source)
+            .field("url", &self.url.as_deref().map(Url::as_str))
+            .field("attempt", &self.attempt)
+            .finish()
+    }
+}
+
+/// Returns the `url` with the value of the `key` query parameter replaced by `***`.
+pub(crate) fn redact(url: &Url) -> Url {
+    let mut url = url.clone();
+
+    if url.query_pairs().any(|(name, _)| name == "key") {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(name, value)| {
+                if name == "key" {
+                    (name.into_owned(), "***".to_string())
+                } else {
+                    (name.into_owned(), value.into_owned())
+                }
+            })
+            .collect();
+
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Error, ErrorKind, HttpResponse, HttpTransport};
+use async_trait::async_trait;
+use bytes::Bytes;
+#[cfg(not(target_arch = "wasm32"))]
+use bytes::BytesMut;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use url::Url;
+
+/// A struct representing a [`HttpTransport`] implemented with [`reqwest::Client`].
+/// Cloning is cheap and clones share the same connection pool.
+#[derive(Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+    max_body_size: Option<u64>,
+}
+
+impl ReqwestTransport {
+    /// Returns a new instance of the [`ReqwestTransport`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the transport aborting downloads of response bodies larger than `limit` bytes
+    /// with [`ErrorKind::BodyTooLarge`].
+    pub fn with_max_body_size(mut self, limit: u64) -> Self {
+        self.max_body_size = Some(limit);
+        self
+    }
+
+    /// Get a reference to the reqwest transport's client.
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Get a reference to the reqwest transport's max body size.
+    pub fn max_body_size(&self) -> Option<u64> {
+        self.max_body_size
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            max_body_size: None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        let kind = if error.is_timeout() {
+            ErrorKind::Timeout
+        } else if is_connect(&error) {
+            ErrorKind::Connect
+        } else if error.is_request() {
+            ErrorKind::Request
+        } else if error.is_body() || error.is_decode() {
+            ErrorKind::Body
+        } else if error.is_builder() {
+            ErrorKind::Config
+        } else {
+            ErrorKind::Other
+        };
+
+        // The url contains the API key.
+        Error::new(kind, error.without_url())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_connect(error: &reqwest::Error) -> bool {
+    error.is_connect()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn is_connect(_error: &reqwest::Error) -> bool {
+    false
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HttpTransport for ReqwestTransport {
+    async fn get(&self, url: &Url) -> Result<HttpResponse, Error> {
+        let response = self.client.get(url.clone()).send().await?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        let body = match self.max_body_size {
+            Some(limit) => {
+                if response
+                    .content_length()
+                    .is_some_and(|length| length > limit)
+                {
+                    return Err(Error::body_too_large(limit));
+                }
+
+                read_body(response, limit).await?
+            }
+            None => response.bytes().await?,
+        };
+
+        Ok(HttpResponse::new(status, headers, body))
+    }
+
+    // `reqwest` runs on tokio outside of browsers, so its timers are available.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_body(mut response: reqwest::Response, limit: u64) -> Result<Bytes, Error> {
+    let mut body = BytesMut::new();
+
+    while let Some(chunk) = response.chunk().await? {
+        if (body.len() + chunk.len()) as u64 > limit {
+            return Err(Error::body_too_large(limit));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body.freeze())
+}
+
+/// The body can't be streamed with `fetch`, so it is checked after the download.
+#[cfg(target_arch = "wasm32")]
+async fn read_body(response: reqwest::Response, limit: u64) -> Result<Bytes, Error> {
+    let body = response.bytes().await?;
+
+    if body.len() as u64 > limit {
+        return Err(Error::body_too_large(limit));
+    }
+
+    Ok(body)
+}
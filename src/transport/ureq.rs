@@ -0,0 +1,210 @@
+use super::{Error, ErrorKind, HttpResponse, HttpTransport};
+use async_trait::async_trait;
+#[cfg(feature = "native-tls")]
+use std::sync::Arc;
+use std::{
+    io::{self, Read},
+    thread,
+    time::Duration,
+};
+use url::Url;
+
+/// A struct representing a synchronous [`HttpTransport`] implemented with [`ureq::Agent`].
+/// Requests and [waits](HttpTransport::sleep) (retry backoff, cooldown, rate limiting) block the current thread,
+/// so the returned futures complete on the first poll and can be driven by any minimal executor
+/// (e.g. [`pollster`](https://docs.rs/pollster)) without an async runtime, whatever other features are enabled.
+/// Cloning is cheap and clones share the same connection pool.
+/// Timeouts and proxies are configured on the agent (see [`ureq::AgentBuilder`]).
+/// # Examples
+/// ```no_run
+/// use scpsl_api::{server_info::RequestParameters, transport::UreqTransport, Client};
+/// use url::Url;
+///
+/// let client = Client::with_transport(UreqTransport::new());
+/// let parameters = RequestParameters::builder()
+///     .url(Url::parse("https://api.scpslgame.com/serverinfo.php").unwrap())
+///     .build();
+///
+/// let response = pollster::block_on(client.get_server_info(&parameters)).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct UreqTransport {
+    agent: ureq::Agent,
+    max_body_size: Option<u64>,
+}
+
+impl Default for UreqTransport {
+    fn default() -> Self {
+        Self::from(default_agent())
+    }
+}
+
+/// Returns an agent using the TLS backend selected with the crate features.
+/// `ureq` only picks `rustls` up by default, the platform library has to be set explicitly.
+fn default_agent() -> ureq::Agent {
+    #[cfg(feature = "native-tls")]
+    if let Ok(connector) = native_tls::TlsConnector::new() {
+        return ureq::AgentBuilder::new()
+            .tls_connector(Arc::new(connector))
+            .build();
+    }
+
+    ureq::Agent::new()
+}
+
+impl UreqTransport {
+    /// Returns a new instance of the [`UreqTransport`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the transport aborting downloads of response bodies larger than `limit` bytes
+    /// with [`ErrorKind::BodyTooLarge`].
+    pub fn with_max_body_size(mut self, limit: u64) -> Self {
+        self.max_body_size = Some(limit);
+        self
+    }
+
+    /// Get a reference to the ureq transport's agent.
+    pub fn agent(&self) -> &ureq::Agent {
+        &self.agent
+    }
+
+    /// Get a reference to the ureq transport's max body size.
+    pub fn max_body_size(&self) -> Option<u64> {
+        self.max_body_size
+    }
+}
+
+impl From<ureq::Agent> for UreqTransport {
+    fn from(agent: ureq::Agent) -> Self {
+        Self {
+            agent,
+            max_body_size: None,
+        }
+    }
+}
+
+impl From<ureq::Transport> for Error {
+    fn from(error: ureq::Transport) -> Self {
+        let kind = match error.kind() {
+            ureq::ErrorKind::Dns | ureq::ErrorKind::ConnectionFailed => ErrorKind::Connect,
+            ureq::ErrorKind::Io if is_timeout(&error) => ErrorKind::Timeout,
+            ureq::ErrorKind::Io | ureq::ErrorKind::TooManyRedirects => ErrorKind::Request,
+            ureq::ErrorKind::BadStatus | ureq::ErrorKind::BadHeader => ErrorKind::Body,
+            _ => ErrorKind::Other,
+        };
+
+        // The url contains the API key, so only the kind, the message and the source are kept.
+        let mut message = error.kind().to_string();
+
+        if let Some(details) = error.message() {
+            message = format!("{}: {}", message, details);
+        }
+        if let Some(source) = std::error::Error::source(&error) {
+            message = format!("{}: {}", message, source);
+        }
+
+        Error::new(kind, message)
+    }
+}
+
+fn is_timeout(error: &ureq::Transport) -> bool {
+    std::error::Error::source(error)
+        .and_then(|source| source.downcast_ref::<io::Error>())
+        .is_some_and(|error| {
+            matches!(
+                error.kind(),
+                io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+            )
+        })
+}
+
+#[async_trait]
+impl HttpTransport for UreqTransport {
+    async fn get(&self, url: &Url) -> Result<HttpResponse, Error> {
+        let response = match self.agent.get(url.as_str()).call() {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(error)) => return Err(error.into()),
+        };
+        let status = response.status();
+        let headers = response
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = response.header(&name)?.to_string();
+
+                Some((name, value))
+            })
+            .collect();
+        let mut body = Vec::new();
+
+        if let Some(limit) = self.max_body_size {
+            let length = response
+                .header("Content-Length")
+                .and_then(|length| length.parse::<u64>().ok());
+
+            if length.is_some_and(|length| length > limit) {
+                return Err(Error::body_too_large(limit));
+            }
+        }
+
+        // One byte over the limit is read to tell a body of exactly `limit` bytes from a larger one.
+        response
+            .into_reader()
+            .take(
+                self.max_body_size
+                    .map_or(u64::MAX, |limit| limit.saturating_add(1)),
+            )
+            .read_to_end(&mut body)
+            .map_err(|error| {
+                let kind = match error.kind() {
+                    io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => ErrorKind::Timeout,
+                    _ => ErrorKind::Body,
+                };
+
+                Error::new(kind, error)
+            })?;
+
+        if let Some(limit) = self
+            .max_body_size
+            .filter(|limit| body.len() as u64 > *limit)
+        {
+            return Err(Error::body_too_large(limit));
+        }
+
+        Ok(HttpResponse::new(status, headers, body.into()))
+    }
+
+    // Waits block the current thread like the requests do.
+    async fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+#[cfg(all(test, any(feature = "native-tls", feature = "rustls-tls")))]
+mod tests {
+    use super::UreqTransport;
+    use crate::transport::HttpTransport;
+    use std::{io::Write, net::TcpListener, thread};
+    use url::Url;
+
+    #[test]
+    fn default_agent_has_tls_backend() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n");
+        });
+        let url = Url::parse(&format!("https://127.0.0.1:{}/", port)).unwrap();
+
+        // The server doesn't speak TLS, so the handshake fails after connecting.
+        let error = pollster::block_on(UreqTransport::new().get(&url))
+            .err()
+            .unwrap();
+
+        server.join().unwrap();
+        assert!(!error.to_string().contains("no TLS backend"), "{}", error);
+    }
+}